@@ -1,5 +1,56 @@
+use bytes::{BufMut, Bytes, BytesMut};
 use commonware_cryptography::{PublicKey, Signature};
-use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, SystemTime};
+use thiserror::Error;
+
+pub mod json;
+pub mod merkle;
+
+use merkle::merkle_root;
+
+/// Errors produced when validating a block
+#[derive(Error, Debug, PartialEq)]
+pub enum BlockError {
+    /// The header's transactions_root does not commit to the block's transactions
+    #[error("Transactions root mismatch: expected {expected}, got {actual}")]
+    TransactionsRootMismatch { expected: String, actual: String },
+
+    /// The block is stamped earlier than its parent
+    #[error("Block timestamp {timestamp} is before parent timestamp {parent_timestamp}")]
+    TimestampBeforeParent { timestamp: u64, parent_timestamp: u64 },
+
+    /// The block is stamped further ahead of the local clock than the allowed drift
+    #[error(
+        "Block timestamp {timestamp} is more than {max_drift_secs}s ahead of local time {now}"
+    )]
+    TimestampTooFarAhead {
+        timestamp: u64,
+        now: u64,
+        max_drift_secs: u64,
+    },
+}
+
+/// Hash function used to derive block hashes, so tests can substitute a cheap
+/// hasher and the algorithm can be swapped in a later upgrade
+pub trait BlockHasher {
+    fn hash(&self, data: &[u8]) -> [u8; 32];
+}
+
+/// SHA-256, the default block hasher
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256BlockHasher;
+
+impl BlockHasher for Sha256BlockHasher {
+    fn hash(&self, data: &[u8]) -> [u8; 32] {
+        let digest = commonware_utils::hash(data);
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&digest);
+        hash
+    }
+}
 
 /// Represents the header portion of a block, containing metadata and cryptographic links
 #[derive(Debug, Clone)]
@@ -14,13 +65,178 @@ pub struct BlockHeader {
     pub utilization: f64,              // Current utilization vs base threshold
 }
 
+impl BlockHeader {
+    /// Serializes the header into its canonical byte representation
+    pub fn encode(&self) -> Bytes {
+        let mut buffer = BytesMut::new();
+
+        buffer.put_u32(self.view);
+        buffer.put_u64(self.height);
+
+        // Convert SystemTime to u64 timestamp
+        let timestamp = self
+            .timestamp
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        buffer.put_u64(timestamp);
+
+        buffer.put_slice(&self.previous_hash);
+        buffer.put_slice(&self.transactions_root);
+        buffer.put_slice(&self.state_root);
+        buffer.put_slice(&self.validator_public_key);
+        buffer.put_f64(self.utilization);
+
+        buffer.freeze()
+    }
+}
+
 /// A complete block containing a header and a list of transactions
+///
+/// Equality and hashing are based on the block hash, not a field-by-field comparison.
+/// Blocks are ordered canonically by height, then by hash bytes.
 #[derive(Debug, Clone)]
 pub struct Block {
     pub header: BlockHeader,
     pub transactions: Vec<Transaction>,
 }
 
+impl Block {
+    /// The canonical genesis block: height 0, an all-zero parent hash and no transactions.
+    ///
+    /// Genesis has no producer, so the validator key is left empty and every node
+    /// derives the same block, and the same hash, from the genesis time alone.
+    pub fn genesis(timestamp: SystemTime) -> Block {
+        let transactions = vec![];
+        Block {
+            header: BlockHeader {
+                view: 0,
+                height: 0,
+                timestamp,
+                previous_hash: [0; 32],
+                transactions_root: merkle_root(&transactions),
+                state_root: [0; 32],
+                validator_public_key: PublicKey::new(),
+                utilization: 0.0,
+            },
+            transactions,
+        }
+    }
+
+    /// Whether this is a genesis block, the only block without a parent
+    pub fn is_genesis(&self) -> bool {
+        self.header.height == 0 && self.header.previous_hash == [0; 32]
+    }
+
+    /// SHA-256 hash of the encoded header, which commits to the transactions via transactions_root
+    pub fn hash(&self) -> [u8; 32] {
+        self.hash_with(&Sha256BlockHasher)
+    }
+
+    /// Hash of the encoded header using `hasher`
+    pub fn hash_with<H: BlockHasher>(&self, hasher: &H) -> [u8; 32] {
+        hasher.hash(&self.header.encode())
+    }
+
+    /// Returns true if this block is a strict ancestor of `other`.
+    ///
+    /// Walks parent links back from `other` using `get_block` to look up blocks by
+    /// hash. A missing parent ends the walk and returns false.
+    pub fn is_ancestor_of<F>(&self, other: &Block, get_block: F) -> bool
+    where
+        F: Fn(&[u8; 32]) -> Option<Block>,
+    {
+        let mut current = other.clone();
+        while current.header.height > self.header.height {
+            match get_block(&current.header.previous_hash) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        current.header.height < other.header.height && current == *self
+    }
+
+    /// Checks that the header is consistent with the block body
+    pub fn validate(&self) -> Result<(), BlockError> {
+        let expected = merkle_root(&self.transactions);
+        if self.header.transactions_root != expected {
+            return Err(BlockError::TransactionsRootMismatch {
+                expected: hex::encode(expected),
+                actual: hex::encode(self.header.transactions_root),
+            });
+        }
+        Ok(())
+    }
+
+    /// Validates the block against its parent and the local clock.
+    ///
+    /// On top of [`Block::validate`], rejects timestamps earlier than the parent's
+    /// or more than `max_drift` past `now`, so a proposer can't stamp blocks
+    /// arbitrarily far in the future.
+    pub fn validate_with_clock(
+        &self,
+        parent: &Block,
+        now: SystemTime,
+        max_drift: Duration,
+    ) -> Result<(), BlockError> {
+        self.validate()?;
+
+        let timestamp = self.header.timestamp;
+        if timestamp < parent.header.timestamp {
+            return Err(BlockError::TimestampBeforeParent {
+                timestamp: unix_secs(timestamp),
+                parent_timestamp: unix_secs(parent.header.timestamp),
+            });
+        }
+
+        if timestamp > now + max_drift {
+            return Err(BlockError::TimestampTooFarAhead {
+                timestamp: unix_secs(timestamp),
+                now: unix_secs(now),
+                max_drift_secs: max_drift.as_secs(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Seconds since the Unix epoch, for error reporting
+fn unix_secs(time: SystemTime) -> u64 {
+    time.duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+impl PartialEq for Block {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash() == other.hash()
+    }
+}
+
+impl Eq for Block {}
+
+impl Hash for Block {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Block::hash(self).hash(state);
+    }
+}
+
+impl PartialOrd for Block {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Block {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.header
+            .height
+            .cmp(&other.header.height)
+            .then_with(|| self.hash().cmp(&other.hash()))
+    }
+}
+
 
 /// A transaction that can be included in a block
 #[derive(Debug, Clone)]
@@ -32,11 +248,210 @@ pub struct Transaction {
     pub signature: Signature,      // Transaction signature
 }
 
+impl Transaction {
+    /// Serializes the transaction into its canonical byte representation
+    pub fn encode(&self) -> Bytes {
+        let mut buffer = BytesMut::new();
+
+        match &self.transaction_type {
+            TransactionType::TokenTransfer { to, amount } => {
+                buffer.put_u8(0);
+                buffer.put_u32(to.len() as u32);
+                buffer.put_slice(to.as_bytes());
+                buffer.put_u64(*amount);
+            }
+        }
+
+        buffer.put_u32(self.from.len() as u32);
+        buffer.put_slice(self.from.as_bytes());
+        buffer.put_u64(self.nonce);
+        buffer.put_u64(self.gas_amount);
+        buffer.put_slice(&self.signature);
+
+        buffer.freeze()
+    }
+}
+
 /// The different types of transactions supported by the system
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum TransactionType {
     TokenTransfer {
         to: String,                // Base58 encoded recipient
         amount: u64,               // Amount in smallest unit (8 decimals)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commonware_cryptography::{Ed25519, Scheme};
+    use std::collections::HashMap;
+
+    fn block(height: u64, previous_hash: [u8; 32], seed: u64) -> Block {
+        Block {
+            header: BlockHeader {
+                view: height as u32,
+                height,
+                timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(height),
+                previous_hash,
+                transactions_root: [0; 32],
+                state_root: [0; 32],
+                validator_public_key: Ed25519::from_seed(seed).public_key(),
+                utilization: 0.0,
+            },
+            transactions: vec![],
+        }
+    }
+
+    fn transfer(to: &str, amount: u64) -> Transaction {
+        Transaction {
+            transaction_type: TransactionType::TokenTransfer {
+                to: to.to_string(),
+                amount,
+            },
+            from: "sender".to_string(),
+            nonce: 0,
+            gas_amount: 21_000,
+            signature: Signature::from_static(&[0; 64]),
+        }
+    }
+
+    fn with_transactions(mut block: Block, transactions: Vec<Transaction>) -> Block {
+        block.header.transactions_root = merkle_root(&transactions);
+        block.transactions = transactions;
+        block
+    }
+
+    #[test]
+    fn test_hash_commits_to_transactions() {
+        let base = block(1, [0; 32], 0);
+        let a = with_transactions(base.clone(), vec![transfer("alice", 10)]);
+        let b = with_transactions(base.clone(), vec![transfer("bob", 10)]);
+
+        assert_eq!(a.header.height, b.header.height);
+        assert_eq!(a.header.timestamp, b.header.timestamp);
+        assert_ne!(a.hash(), b.hash());
+        assert_ne!(a.hash(), base.hash());
+    }
+
+    #[test]
+    fn test_genesis() {
+        let timestamp = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let genesis = Block::genesis(timestamp);
+        assert!(genesis.is_genesis());
+        assert_eq!(genesis.validate(), Ok(()));
+        assert_eq!(genesis, Block::genesis(timestamp));
+
+        let child = block(1, genesis.hash(), 0);
+        assert!(!child.is_genesis());
+        assert!(genesis.is_ancestor_of(&child, |_| Some(genesis.clone())));
+    }
+
+    #[test]
+    fn test_hash_with_custom_hasher() {
+        struct FixedHasher([u8; 32]);
+
+        impl BlockHasher for FixedHasher {
+            fn hash(&self, _data: &[u8]) -> [u8; 32] {
+                self.0
+            }
+        }
+
+        let block = block(1, [0; 32], 0);
+        assert_eq!(block.hash_with(&FixedHasher([7; 32])), [7; 32]);
+        assert_eq!(block.hash_with(&Sha256BlockHasher), block.hash());
+        assert_ne!(block.hash(), [7; 32]);
+    }
+
+    #[test]
+    fn test_validate_transactions_root() {
+        assert_eq!(block(0, [0; 32], 0).validate(), Ok(()));
+
+        let mut valid = with_transactions(block(1, [0; 32], 0), vec![transfer("alice", 10)]);
+        assert_eq!(valid.validate(), Ok(()));
+
+        valid.transactions.push(transfer("bob", 5));
+        assert!(matches!(
+            valid.validate(),
+            Err(BlockError::TransactionsRootMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_with_clock() {
+        let parent = block(1, [0; 32], 0);
+        let child = block(2, parent.hash(), 0);
+        let max_drift = Duration::from_secs(15);
+
+        // Stamped a few seconds ahead of the local clock is within the window
+        let now = child.header.timestamp - Duration::from_secs(5);
+        assert_eq!(child.validate_with_clock(&parent, now, max_drift), Ok(()));
+
+        // An hour ahead is not
+        let now = child.header.timestamp - Duration::from_secs(3600);
+        assert!(matches!(
+            child.validate_with_clock(&parent, now, max_drift),
+            Err(BlockError::TimestampTooFarAhead { .. })
+        ));
+
+        let now = child.header.timestamp;
+        assert!(matches!(
+            parent.validate_with_clock(&child, now, max_drift),
+            Err(BlockError::TimestampBeforeParent { .. })
+        ));
+    }
+
+    #[test]
+    fn test_canonical_ordering() {
+        let genesis = block(0, [0; 32], 0);
+        let a = block(1, genesis.hash(), 1);
+        let b = block(1, genesis.hash(), 2);
+
+        // Different heights order by height
+        assert!(genesis < a);
+        assert!(genesis < b);
+
+        // Same height orders by hash bytes
+        assert_ne!(a, b);
+        assert_eq!(a.cmp(&b), a.hash().cmp(&b.hash()));
+
+        let mut blocks = [b.clone(), a.clone(), genesis.clone()];
+        blocks.sort();
+        assert_eq!(blocks[0], genesis);
+    }
+
+    #[test]
+    fn test_equality_is_hash_based() {
+        let a = block(1, [0; 32], 1);
+        let mut b = a.clone();
+        assert_eq!(a, b);
+
+        b.header.state_root = [1; 32];
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_is_ancestor_of() {
+        let mut chain = vec![block(0, [0; 32], 0)];
+        for height in 1..5 {
+            let parent = chain.last().unwrap().hash();
+            chain.push(block(height, parent, 0));
+        }
+        let fork = block(3, chain[1].hash(), 1);
+
+        let store: HashMap<[u8; 32], Block> = chain
+            .iter()
+            .chain([&fork])
+            .map(|b| (b.hash(), b.clone()))
+            .collect();
+        let get_block = |hash: &[u8; 32]| store.get(hash).cloned();
+
+        assert!(chain[0].is_ancestor_of(&chain[4], get_block));
+        assert!(chain[2].is_ancestor_of(&chain[4], get_block));
+        assert!(chain[1].is_ancestor_of(&fork, get_block));
+        assert!(!chain[2].is_ancestor_of(&fork, get_block));
+        assert!(!chain[4].is_ancestor_of(&chain[2], get_block));
+        assert!(!chain[4].is_ancestor_of(&chain[4], get_block));
+    }
+}
@@ -0,0 +1,230 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime};
+use thiserror::Error;
+
+use super::{Block, BlockHeader, Transaction, TransactionType};
+use crate::utils::hex::{decode_hash, decode_pubkey, decode_signature, encode_hash, HexError};
+
+/// Errors produced when reading a block from its JSON form
+#[derive(Error, Debug)]
+pub enum BlockJsonError {
+    #[error("Invalid block JSON: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Invalid hex field: {0}")]
+    Hex(#[from] HexError),
+
+    /// The `hash` field does not match the hash of the decoded header
+    #[error("Block hash mismatch: expected {expected}, got {actual}")]
+    HashMismatch { expected: String, actual: String },
+
+    /// The timestamp cannot be represented as a system time
+    #[error("Block timestamp {0} is out of range")]
+    TimestampOutOfRange(u64),
+}
+
+/// JSON representation of a block for RPC consumers.
+///
+/// Hashes, keys and signatures are hex strings rather than arrays of integers,
+/// and the timestamp is in Unix seconds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockJson {
+    pub hash: String,
+    pub view: u32,
+    pub height: u64,
+    pub timestamp: u64,
+    pub previous_hash: String,
+    pub transactions_root: String,
+    pub state_root: String,
+    pub validator_public_key: String,
+    pub utilization: f64,
+    pub transactions: Vec<TransactionJson>,
+}
+
+/// JSON representation of a transaction with a hex-encoded signature
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionJson {
+    #[serde(flatten)]
+    pub transaction_type: TransactionType,
+    pub from: String,
+    pub nonce: u64,
+    pub gas_amount: u64,
+    pub signature: String,
+}
+
+impl From<&Block> for BlockJson {
+    fn from(block: &Block) -> Self {
+        let header = &block.header;
+        Self {
+            hash: encode_hash(&block.hash()),
+            view: header.view,
+            height: header.height,
+            timestamp: super::unix_secs(header.timestamp),
+            previous_hash: encode_hash(&header.previous_hash),
+            transactions_root: encode_hash(&header.transactions_root),
+            state_root: encode_hash(&header.state_root),
+            validator_public_key: hex::encode(&header.validator_public_key),
+            utilization: header.utilization,
+            transactions: block
+                .transactions
+                .iter()
+                .map(TransactionJson::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&Transaction> for TransactionJson {
+    fn from(transaction: &Transaction) -> Self {
+        Self {
+            transaction_type: transaction.transaction_type.clone(),
+            from: transaction.from.clone(),
+            nonce: transaction.nonce,
+            gas_amount: transaction.gas_amount,
+            signature: hex::encode(&transaction.signature),
+        }
+    }
+}
+
+impl TryFrom<BlockJson> for Block {
+    type Error = BlockJsonError;
+
+    /// Decodes the hex fields and checks the stated hash against the header
+    fn try_from(json: BlockJson) -> Result<Self, Self::Error> {
+        let transactions = json
+            .transactions
+            .into_iter()
+            .map(Transaction::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let timestamp = SystemTime::UNIX_EPOCH
+            .checked_add(Duration::from_secs(json.timestamp))
+            .ok_or(BlockJsonError::TimestampOutOfRange(json.timestamp))?;
+
+        let block = Block {
+            header: BlockHeader {
+                view: json.view,
+                height: json.height,
+                timestamp,
+                previous_hash: decode_hash(&json.previous_hash)?,
+                transactions_root: decode_hash(&json.transactions_root)?,
+                state_root: decode_hash(&json.state_root)?,
+                validator_public_key: decode_pubkey(&json.validator_public_key)?,
+                utilization: json.utilization,
+            },
+            transactions,
+        };
+
+        let actual = encode_hash(&block.hash());
+        if actual != json.hash {
+            return Err(BlockJsonError::HashMismatch {
+                expected: json.hash,
+                actual,
+            });
+        }
+        Ok(block)
+    }
+}
+
+impl TryFrom<TransactionJson> for Transaction {
+    type Error = HexError;
+
+    fn try_from(json: TransactionJson) -> Result<Self, Self::Error> {
+        Ok(Transaction {
+            transaction_type: json.transaction_type,
+            from: json.from,
+            nonce: json.nonce,
+            gas_amount: json.gas_amount,
+            signature: decode_signature(&json.signature)?,
+        })
+    }
+}
+
+impl Block {
+    /// Serializes the block as JSON with hex-encoded hashes
+    pub fn to_json(&self) -> Result<String, BlockJsonError> {
+        Ok(serde_json::to_string(&BlockJson::from(self))?)
+    }
+
+    /// Parses a block from the JSON produced by [`Block::to_json`]
+    pub fn from_json(s: &str) -> Result<Self, BlockJsonError> {
+        let json: BlockJson = serde_json::from_str(s)?;
+        Block::try_from(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::merkle::merkle_root;
+    use commonware_cryptography::{Ed25519, Scheme};
+
+    fn block() -> Block {
+        let mut signer = Ed25519::from_seed(0);
+        let transactions = vec![Transaction {
+            transaction_type: TransactionType::TokenTransfer {
+                to: "bob".to_string(),
+                amount: 25,
+            },
+            from: "alice".to_string(),
+            nonce: 3,
+            gas_amount: 21_000,
+            signature: signer.sign(b"romer", b"transfer"),
+        }];
+
+        Block {
+            header: BlockHeader {
+                view: 4,
+                height: 4,
+                timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+                previous_hash: [3; 32],
+                transactions_root: merkle_root(&transactions),
+                state_root: [5; 32],
+                validator_public_key: signer.public_key(),
+                utilization: 0.5,
+            },
+            transactions,
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let block = block();
+        let json = block.to_json().unwrap();
+
+        // Hashes are hex strings, not arrays of integers
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["hash"], encode_hash(&block.hash()));
+        assert_eq!(value["state_root"], "05".repeat(32));
+        assert_eq!(value["transactions"][0]["type"], "token_transfer");
+
+        let decoded = Block::from_json(&json).unwrap();
+        assert_eq!(decoded, block);
+        assert_eq!(
+            decoded.transactions[0].encode(),
+            block.transactions[0].encode()
+        );
+    }
+
+    #[test]
+    fn test_from_json_rejects_wrong_hash() {
+        let mut json = BlockJson::from(&block());
+        json.hash = encode_hash(&[0; 32]);
+
+        assert!(matches!(
+            Block::try_from(json),
+            Err(BlockJsonError::HashMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_json_rejects_out_of_range_timestamp() {
+        let mut json = BlockJson::from(&block());
+        json.timestamp = u64::MAX;
+
+        assert!(matches!(
+            Block::try_from(json),
+            Err(BlockJsonError::TimestampOutOfRange(u64::MAX))
+        ));
+    }
+}
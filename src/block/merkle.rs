@@ -0,0 +1,204 @@
+use bytes::{BufMut, BytesMut};
+
+use super::Transaction;
+
+/// Computes the SHA-256 Merkle root of an ordered list of transactions.
+///
+/// Leaves are the hashes of each transaction's canonical encoding. When a level
+/// has an odd number of nodes the last one is paired with itself. An empty list
+/// has the all-zero root.
+pub fn merkle_root(transactions: &[Transaction]) -> [u8; 32] {
+    let mut level = leaves(transactions);
+    if level.is_empty() {
+        return [0; 32];
+    }
+
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level[0]
+}
+
+/// Returns the sibling hashes from the leaf at `index` up to the root, or None
+/// if `index` is out of range
+// Neither proof helper has a caller outside tests until light clients can
+// request inclusion proofs
+#[allow(dead_code)]
+pub fn merkle_proof(transactions: &[Transaction], index: usize) -> Option<Vec<[u8; 32]>> {
+    if index >= transactions.len() {
+        return None;
+    }
+
+    let mut level = leaves(transactions);
+    let mut position = index;
+    let mut proof = Vec::new();
+
+    while level.len() > 1 {
+        let sibling = if position % 2 == 1 {
+            level[position - 1]
+        } else {
+            // The last node of an odd level is its own sibling
+            *level.get(position + 1).unwrap_or(&level[position])
+        };
+        proof.push(sibling);
+
+        level = next_level(&level);
+        position /= 2;
+    }
+
+    Some(proof)
+}
+
+/// Checks that `transaction` sits at `index` under `root` given its sibling path
+#[allow(dead_code)]
+pub fn verify_proof(
+    root: &[u8; 32],
+    transaction: &Transaction,
+    index: usize,
+    proof: &[[u8; 32]],
+) -> bool {
+    let mut hash = sha256(&transaction.encode());
+    let mut position = index;
+
+    for sibling in proof {
+        hash = if position % 2 == 1 {
+            hash_pair(sibling, &hash)
+        } else {
+            hash_pair(&hash, sibling)
+        };
+        position /= 2;
+    }
+
+    position == 0 && hash == *root
+}
+
+fn leaves(transactions: &[Transaction]) -> Vec<[u8; 32]> {
+    transactions
+        .iter()
+        .map(|transaction| sha256(&transaction.encode()))
+        .collect()
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&pair[0])))
+        .collect()
+}
+
+fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut buffer = BytesMut::with_capacity(64);
+    buffer.put_slice(left);
+    buffer.put_slice(right);
+    sha256(&buffer)
+}
+
+fn sha256(data: &[u8]) -> [u8; 32] {
+    let digest = commonware_utils::hash(data);
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&digest);
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::TransactionType;
+    use commonware_cryptography::Signature;
+
+    fn transactions(count: u64) -> Vec<Transaction> {
+        (0..count)
+            .map(|nonce| Transaction {
+                transaction_type: TransactionType::TokenTransfer {
+                    to: "recipient".to_string(),
+                    amount: 1,
+                },
+                from: "sender".to_string(),
+                nonce,
+                gas_amount: 21_000,
+                signature: Signature::from_static(&[0; 64]),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_empty_root() {
+        assert_eq!(merkle_root(&[]), [0; 32]);
+        assert_eq!(merkle_proof(&[], 0), None);
+    }
+
+    #[test]
+    fn test_single_transaction() {
+        let transactions = transactions(1);
+        let root = merkle_root(&transactions);
+        assert_eq!(root, sha256(&transactions[0].encode()));
+
+        let proof = merkle_proof(&transactions, 0).unwrap();
+        assert!(proof.is_empty());
+        assert!(verify_proof(&root, &transactions[0], 0, &proof));
+    }
+
+    #[test]
+    fn test_odd_count_duplicates_last_node() {
+        let transactions = transactions(3);
+        let leaves = leaves(&transactions);
+        let expected = hash_pair(
+            &hash_pair(&leaves[0], &leaves[1]),
+            &hash_pair(&leaves[2], &leaves[2]),
+        );
+        assert_eq!(merkle_root(&transactions), expected);
+    }
+
+    #[test]
+    fn test_proofs_verify() {
+        for count in 1..=7 {
+            let transactions = transactions(count);
+            let root = merkle_root(&transactions);
+
+            for (index, transaction) in transactions.iter().enumerate() {
+                let proof = merkle_proof(&transactions, index).unwrap();
+                assert!(verify_proof(&root, transaction, index, &proof));
+
+                // The same proof does not place the transaction anywhere else
+                let other = (index + 1) % transactions.len();
+                if other != index {
+                    assert!(!verify_proof(&root, transaction, other, &proof));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_tampered_proofs_rejected() {
+        let transactions = transactions(5);
+        let root = merkle_root(&transactions);
+        let proof = merkle_proof(&transactions, 2).unwrap();
+
+        // A modified sibling no longer leads to the root
+        let mut tampered = proof.clone();
+        tampered[1][0] ^= 1;
+        assert!(!verify_proof(&root, &transactions[2], 2, &tampered));
+
+        // A truncated path stops short of the root
+        assert!(!verify_proof(
+            &root,
+            &transactions[2],
+            2,
+            &proof[..proof.len() - 1]
+        ));
+
+        // A different transaction can't reuse the proof
+        let mut forged = transactions[2].clone();
+        forged.nonce += 100;
+        assert!(!verify_proof(&root, &forged, 2, &proof));
+
+        // Nor can the proof be checked against another block's root
+        assert!(!verify_proof(
+            &merkle_root(&transactions[..4]),
+            &transactions[2],
+            2,
+            &proof
+        ));
+        assert_eq!(merkle_proof(&transactions, 5), None);
+    }
+}
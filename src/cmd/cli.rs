@@ -1,5 +1,6 @@
-use clap::{Parser, command};
+use clap::{Args, Parser, Subcommand, command};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -9,6 +10,37 @@ use std::net::SocketAddr;
     about = "A blockchain with physical infrastructure requirements"
 )]
 pub struct NodeCliArgs {
+    #[command(subcommand)]
+    pub command: Command,
+
+    /// Log level for node operation
+    #[arg(
+        short,
+        long,
+        global = true,
+        default_value = "info",
+        help = "Set the logging level",
+        value_parser = ["error", "warn", "info", "debug", "trace"]
+    )]
+    pub log_level: String,
+}
+
+impl NodeCliArgs {
+    pub fn get_log_level(&self) -> tracing::Level {
+        match self.log_level.as_str() {
+            "error" => tracing::Level::ERROR,
+            "warn" => tracing::Level::WARN,
+            "info" => tracing::Level::INFO,
+            "debug" => tracing::Level::DEBUG,
+            "trace" => tracing::Level::TRACE,
+            _ => tracing::Level::INFO,
+        }
+    }
+}
+
+/// Arguments for running the validator node
+#[derive(Args, Debug)]
+pub struct RunArgs {
     /// Network address for this node in the format IP:PORT
     #[arg(
         short, 
@@ -36,32 +68,223 @@ pub struct NodeCliArgs {
     )]
     pub bootstrap: Option<String>,
 
-    /// Log level for node operation
+    /// Name of the validator key to load, for hosts running several validators
     #[arg(
-        short,
         long,
-        default_value = "info",
-        help = "Set the logging level",
-        value_parser = ["error", "warn", "info", "debug", "trace"]
+        help = "Load the named key node-<NAME>.key instead of node.key"
     )]
-    pub log_level: String,
-}
+    pub key_name: Option<String>,
 
-impl NodeCliArgs {
-    pub fn get_log_level(&self) -> tracing::Level {
-        match self.log_level.as_str() {
-            "error" => tracing::Level::ERROR,
-            "warn" => tracing::Level::WARN,
-            "info" => tracing::Level::INFO,
-            "debug" => tracing::Level::DEBUG,
-            "trace" => tracing::Level::TRACE,
-            _ => tracing::Level::INFO,
-        }
-    }
+    /// Directory holding genesis.toml, storage.toml, validator.toml and regions.toml
+    #[arg(
+        long,
+        help = "Load configuration files from this directory instead of ./config"
+    )]
+    pub config_dir: Option<PathBuf>,
 
+    /// Follow the chain without taking part in consensus
+    #[arg(
+        long,
+        help = "Run as an observer that syncs blocks but never proposes"
+    )]
+    pub observer: bool,
+}
+
+impl RunArgs {
     pub fn get_bootstrap_addr(&self) -> Option<SocketAddr> {
         self.bootstrap
             .as_ref()
             .map(|addr| addr.parse().expect("Invalid bootstrap address"))
     }
-}
\ No newline at end of file
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Start the validator node
+    Run(RunArgs),
+
+    /// Generate a new validator key and print its public key
+    Keygen {
+        /// Name of the key to generate
+        #[arg(
+            long,
+            help = "Generate the named key node-<NAME>.key instead of node.key"
+        )]
+        key_name: Option<String>,
+
+        /// Replace a key that already exists
+        #[arg(
+            long,
+            help = "Overwrite an existing key file"
+        )]
+        force: bool,
+    },
+
+    /// Report how this host measures up to the validator hardware requirements
+    Benchmark {
+        /// Directory whose free space is checked, normally the chain data directory
+        #[arg(
+            long,
+            default_value = ".",
+            help = "Directory to measure free disk space in"
+        )]
+        data_dir: PathBuf,
+    },
+
+    /// Manage node configuration files
+    Config {
+        #[command(subcommand)]
+        action: ConfigCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigCommand {
+    /// Write commented default configuration files
+    Init {
+        /// Directory to write the configuration files into
+        #[arg(
+            long,
+            default_value = "config",
+            help = "Directory to write the configuration files into"
+        )]
+        dir: PathBuf,
+
+        /// Overwrite configuration files that already exist
+        #[arg(
+            long,
+            help = "Overwrite existing configuration files"
+        )]
+        force: bool,
+    },
+
+    /// List the regions validators are authorized to operate from
+    Regions {
+        /// Directory holding regions.toml
+        #[arg(
+            long,
+            help = "Load regions.toml from this directory instead of ./config"
+        )]
+        config_dir: Option<PathBuf>,
+
+        /// Show only the region closest to a position
+        #[arg(
+            long,
+            num_args = 2,
+            value_names = ["LATITUDE", "LONGITUDE"],
+            allow_negative_numbers = true,
+            help = "Show the region nearest to a latitude and longitude"
+        )]
+        near: Option<Vec<f64>>,
+
+        /// Show only the regions in one country
+        #[arg(
+            long,
+            conflicts_with = "near",
+            help = "Show only the regions in this country"
+        )]
+        country: Option<String>,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_run() {
+        let args = NodeCliArgs::try_parse_from(["romer", "run", "-g", "-l", "debug"]).unwrap();
+        assert_eq!(args.log_level, "debug");
+        match args.command {
+            Command::Run(run) => {
+                assert!(run.genesis);
+                assert_eq!(run.address, "127.0.0.1:8000".parse().unwrap());
+                assert_eq!(run.config_dir, None);
+                assert!(!run.observer);
+            }
+            command => panic!("unexpected command: {:?}", command),
+        }
+
+        // A non-genesis node must be given a bootstrap address
+        assert!(NodeCliArgs::try_parse_from(["romer", "run"]).is_err());
+
+        let args =
+            NodeCliArgs::try_parse_from(["romer", "run", "-g", "--config-dir", "/etc/romer"])
+                .unwrap();
+        match args.command {
+            Command::Run(run) => assert_eq!(run.config_dir, Some(PathBuf::from("/etc/romer"))),
+            command => panic!("unexpected command: {:?}", command),
+        }
+    }
+
+    #[test]
+    fn test_parse_keygen() {
+        let args = NodeCliArgs::try_parse_from(["romer", "keygen", "--key-name", "alpha"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Keygen {
+                key_name: Some(ref name),
+                force: false
+            } if name == "alpha"
+        ));
+    }
+
+    #[test]
+    fn test_parse_benchmark() {
+        let args =
+            NodeCliArgs::try_parse_from(["romer", "benchmark", "--data-dir", "/data"]).unwrap();
+        match args.command {
+            Command::Benchmark { data_dir } => assert_eq!(data_dir, PathBuf::from("/data")),
+            command => panic!("unexpected command: {:?}", command),
+        }
+    }
+
+    #[test]
+    fn test_parse_config_init() {
+        let args = NodeCliArgs::try_parse_from(["romer", "config", "init", "--force"]).unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Config {
+                action: ConfigCommand::Init { force: true, .. }
+            }
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_regions() {
+        let args = NodeCliArgs::try_parse_from([
+            "romer", "config", "regions", "--near", "-33.87", "151.21",
+        ])
+        .unwrap();
+        match args.command {
+            Command::Config {
+                action:
+                    ConfigCommand::Regions {
+                        config_dir,
+                        near,
+                        country,
+                    },
+            } => {
+                assert_eq!(config_dir, None);
+                assert_eq!(near, Some(vec![-33.87, 151.21]));
+                assert_eq!(country, None);
+            }
+            command => panic!("unexpected command: {:?}", command),
+        }
+
+        let args =
+            NodeCliArgs::try_parse_from(["romer", "config", "regions", "--country", "Australia"])
+                .unwrap();
+        assert!(matches!(
+            args.command,
+            Command::Config {
+                action: ConfigCommand::Regions { country: Some(ref country), .. }
+            } if country == "Australia"
+        ));
+
+        // A position needs both coordinates
+        assert!(
+            NodeCliArgs::try_parse_from(["romer", "config", "regions", "--near", "1.0"]).is_err()
+        );
+    }
+}
@@ -4,6 +4,8 @@ use std::fs;
 use std::env;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::config::template::DEFAULT_CONFIG_DIR;
+
 /// Error type for genesis configuration operations
 #[derive(Debug)]
 pub enum ConfigError {
@@ -57,6 +59,13 @@ pub struct NetworkConfig {
     pub chain_id: String,
     pub version: String,
     pub genesis_time: u64,
+    /// How far in the future (in seconds) genesis_time may be when the node starts
+    #[serde(default = "default_max_genesis_delay_secs")]
+    pub max_genesis_delay_secs: u64,
+}
+
+fn default_max_genesis_delay_secs() -> u64 {
+    defaults::DEFAULT_MAX_GENESIS_DELAY_SECS
 }
 
 /// Parameters that control how consensus operates
@@ -66,6 +75,37 @@ pub struct ConsensusConfig {
     pub epoch_length: u64,
     pub min_validators: u32,
     pub max_validators: u32,
+    /// Wall-clock window (in milliseconds) in which a validator must show activity
+    #[serde(default = "default_activity_timeout_ms")]
+    pub activity_timeout_ms: u64,
+    /// Base units credited to the proposer of each finalized block, on top of its fees
+    #[serde(default = "default_block_reward")]
+    pub block_reward: u64,
+    /// How far (in milliseconds) a proposed block's timestamp may run ahead of the local clock
+    #[serde(default = "default_max_block_drift_ms")]
+    pub max_block_drift_ms: u64,
+}
+
+fn default_activity_timeout_ms() -> u64 {
+    defaults::DEFAULT_ACTIVITY_TIMEOUT_MS
+}
+
+fn default_block_reward() -> u64 {
+    defaults::DEFAULT_BLOCK_REWARD
+}
+
+fn default_max_block_drift_ms() -> u64 {
+    defaults::DEFAULT_MAX_BLOCK_DRIFT_MS
+}
+
+impl ConsensusConfig {
+    /// Activity timeout expressed in consensus views, the unit the engine expects.
+    ///
+    /// One view is produced per block interval, so this is the wall-clock activity
+    /// window divided by the block time.
+    pub fn activity_timeout_views(&self) -> u64 {
+        self.activity_timeout_ms / self.block_time_ms
+    }
 }
 
 /// Configuration for the peer-to-peer networking layer
@@ -84,12 +124,68 @@ pub struct TechnicalConfig {
     pub max_tx_size: u32,
 }
 
+/// Commented genesis configuration written by `config init`.
+/// `{genesis_time}` is replaced with the time the template is generated.
+const TEMPLATE: &str = r#"# Rømer Chain Genesis Configuration
+
+[network]
+# A unique identifier for this blockchain network instance
+chain_id = "romer-dev"
+# Software version for compatibility checking
+version = "0.1.0"
+# PLACEHOLDER: the timestamp when this network starts (Unix timestamp).
+# Every genesis operator must agree on this value before launch.
+genesis_time = {genesis_time}
+# Refuse to start if genesis_time is further than this in the future (seconds).
+# Guards against a mistyped timestamp leaving the node waiting indefinitely.
+max_genesis_delay_secs = 2592000
+
+# PLACEHOLDER: hex-encoded public keys of the validators present at genesis.
+# initial_validators = ["<validator public key>"]
+
+[consensus]
+# How often we create new blocks (in milliseconds)
+block_time_ms = 1000
+# How many blocks make up an epoch - used for scheduling changes in validator sets
+epoch_length = 1000
+# Bounds on the size of the validator set
+min_validators = 1
+max_validators = 100
+# How long a validator may go without activity before it is considered inactive
+# (milliseconds). Converted to consensus views by dividing by block_time_ms.
+activity_timeout_ms = 100000
+# Base units paid to the proposer of each finalized block, on top of its fees
+block_reward = 0
+# How far a proposed block's timestamp may run ahead of our clock (milliseconds)
+max_block_drift_ms = 15000
+
+[networking]
+# Maximum number of connected peers
+max_peers = 50
+# Maximum size of any network message (1MB)
+max_message_size = 1048576
+# How long to wait when connecting to peers (milliseconds)
+connection_timeout_ms = 5000
+# How often to look for new peers (seconds)
+peer_discovery_interval = 30
+
+[technical]
+# Maximum size of a block in bytes (1MB)
+max_block_size = 1048576
+# Maximum size of a single transaction (64KB)
+max_tx_size = 65536
+"#;
+
 /// Default values for configuration parameters
 pub mod defaults {
+    pub const DEFAULT_MAX_GENESIS_DELAY_SECS: u64 = 30 * 24 * 60 * 60;  // 30 days
     pub const DEFAULT_BLOCK_TIME_MS: u64 = 1000;
     pub const DEFAULT_EPOCH_LENGTH: u64 = 1000;
     pub const DEFAULT_MIN_VALIDATORS: u32 = 1;
     pub const DEFAULT_MAX_VALIDATORS: u32 = 100;
+    pub const DEFAULT_ACTIVITY_TIMEOUT_MS: u64 = 100_000;
+    pub const DEFAULT_BLOCK_REWARD: u64 = 0;                // fees only
+    pub const DEFAULT_MAX_BLOCK_DRIFT_MS: u64 = 15_000;
     pub const DEFAULT_MAX_PEERS: u32 = 50;
     pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 1024 * 1024;  // 1MB
     pub const DEFAULT_CONNECTION_TIMEOUT_MS: u32 = 5000;
@@ -99,9 +195,9 @@ pub mod defaults {
 }
 
 impl GenesisConfig {
-    /// Loads the configuration from the default location
-    pub fn load_default() -> Result<Self, ConfigError> {
-        let config_path = Self::default_config_path()?;
+    /// Loads the configuration from `config_dir`, or the default location when `None`
+    pub fn load_default(config_dir: Option<&Path>) -> Result<Self, ConfigError> {
+        let config_path = Self::default_config_path(config_dir)?;
         Self::load(&config_path)
     }
 
@@ -113,15 +209,31 @@ impl GenesisConfig {
         Ok(config)
     }
 
+    /// Writes a commented genesis configuration template to `path`.
+    ///
+    /// Refuses to replace an existing file unless `force` is set.
+    pub fn write_template<P: AsRef<Path>>(path: P, force: bool) -> Result<(), ConfigError> {
+        let genesis_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let contents = TEMPLATE.replace("{genesis_time}", &genesis_time.to_string());
+        crate::config::template::write_file(path.as_ref(), &contents, force)?;
+        Ok(())
+    }
+
     /// Determines the default configuration path
-    fn default_config_path() -> Result<PathBuf, ConfigError> {
-        // First check if path is specified in environment
-        if let Ok(path) = env::var("ROMER_CONFIG") {
-            return Ok(PathBuf::from(path));
+    fn default_config_path(config_dir: Option<&Path>) -> Result<PathBuf, ConfigError> {
+        // First check if path is specified in environment, unless a config
+        // directory was given explicitly
+        if config_dir.is_none() {
+            if let Ok(path) = env::var("ROMER_CONFIG") {
+                return Ok(PathBuf::from(path));
+            }
         }
 
         // Then check in the config directory relative to the project root
-        let config_dir = PathBuf::from("config");
+        let config_dir = config_dir.unwrap_or(Path::new(DEFAULT_CONFIG_DIR));
         
         // Check for environment-specific config first
         let env = env::var("ROMER_ENV").unwrap_or_else(|_| "development".to_string());
@@ -151,12 +263,16 @@ impl GenesisConfig {
                     .duration_since(UNIX_EPOCH)
                     .unwrap()
                     .as_secs(),
+                max_genesis_delay_secs: defaults::DEFAULT_MAX_GENESIS_DELAY_SECS,
             },
             consensus: ConsensusConfig {
                 block_time_ms: defaults::DEFAULT_BLOCK_TIME_MS,
                 epoch_length: defaults::DEFAULT_EPOCH_LENGTH,
                 min_validators: defaults::DEFAULT_MIN_VALIDATORS,
                 max_validators: defaults::DEFAULT_MAX_VALIDATORS,
+                activity_timeout_ms: defaults::DEFAULT_ACTIVITY_TIMEOUT_MS,
+                block_reward: defaults::DEFAULT_BLOCK_REWARD,
+                max_block_drift_ms: defaults::DEFAULT_MAX_BLOCK_DRIFT_MS,
             },
             networking: NetworkingConfig {
                 max_peers: defaults::DEFAULT_MAX_PEERS,
@@ -171,6 +287,30 @@ impl GenesisConfig {
         }
     }
 
+    /// Checks that genesis_time is set and not absurdly far ahead of `now`.
+    ///
+    /// Called at node startup, where a genesis time years in the future would
+    /// otherwise leave the node silently waiting for a network that never starts.
+    pub fn validate_genesis_time(&self, now: SystemTime) -> Result<(), ConfigError> {
+        let genesis_time = self.network.genesis_time;
+        if genesis_time == 0 {
+            return Err(ConfigError::ValidationError(
+                "Genesis time must be set to a Unix timestamp".to_string(),
+            ));
+        }
+
+        let now = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let latest = now.saturating_add(self.network.max_genesis_delay_secs);
+        if genesis_time > latest {
+            return Err(ConfigError::ValidationError(format!(
+                "Genesis time {} is more than {} seconds after the current time {}",
+                genesis_time, self.network.max_genesis_delay_secs, now
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Validates the configuration values
     fn validate(&self) -> Result<(), ConfigError> {
         // Validate network configuration
@@ -199,12 +339,24 @@ impl GenesisConfig {
             ));
         }
 
+        if self.consensus.min_validators == 0 {
+            return Err(ConfigError::ValidationError(
+                "Minimum validators must be at least 1".to_string()
+            ));
+        }
+
         if self.consensus.max_validators < self.consensus.min_validators {
             return Err(ConfigError::ValidationError(
                 "Maximum validators must be greater than minimum validators".to_string()
             ));
         }
 
+        if self.consensus.activity_timeout_views() < 1 {
+            return Err(ConfigError::ValidationError(
+                "Activity timeout must span at least one block time".to_string()
+            ));
+        }
+
         // Validate networking configuration
         if self.networking.max_message_size > 10 * 1024 * 1024 {
             return Err(ConfigError::ValidationError(
@@ -232,6 +384,7 @@ impl GenesisConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::time::Duration;
 
     #[test]
     fn test_development_config() {
@@ -253,6 +406,22 @@ mod tests {
             Err(ConfigError::ValidationError(_))
         ));
 
+        // Reset and test a validator set that may be empty
+        config = GenesisConfig::development();
+        config.consensus.min_validators = 0;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ValidationError(_))
+        ));
+
+        // Reset and test an activity timeout shorter than one block
+        config = GenesisConfig::development();
+        config.consensus.activity_timeout_ms = config.consensus.block_time_ms - 1;
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::ValidationError(_))
+        ));
+
         // Reset and test invalid message size
         config = GenesisConfig::development();
         config.networking.max_message_size = 20 * 1024 * 1024;
@@ -271,6 +440,29 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_validate_genesis_time() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mut config = GenesisConfig::development();
+
+        // An hour away is fine
+        config.network.genesis_time = 1_700_003_600;
+        assert!(config.validate_genesis_time(now).is_ok());
+
+        // The year 3000 is not
+        config.network.genesis_time = 32_503_680_000;
+        assert!(matches!(
+            config.validate_genesis_time(now),
+            Err(ConfigError::ValidationError(_))
+        ));
+
+        config.network.genesis_time = 0;
+        assert!(matches!(
+            config.validate_genesis_time(now),
+            Err(ConfigError::ValidationError(_))
+        ));
+    }
+
     #[test]
     fn test_serialization() {
         let config = GenesisConfig::development();
@@ -282,4 +474,25 @@ mod tests {
         assert_eq!(config.networking.max_peers, deserialized.networking.max_peers);
         assert_eq!(config.technical.max_block_size, deserialized.technical.max_block_size);
     }
+
+    #[test]
+    fn test_activity_timeout_views() {
+        let mut config = GenesisConfig::development();
+        config.consensus.block_time_ms = 1000;
+        config.consensus.activity_timeout_ms = 100_000;
+        assert_eq!(config.consensus.activity_timeout_views(), 100);
+
+        config.consensus.block_time_ms = 250;
+        assert_eq!(config.consensus.activity_timeout_views(), 400);
+
+        // Genesis files written before the activity timeout existed still load
+        let contents = TEMPLATE
+            .replace("{genesis_time}", "1700000000")
+            .replace("activity_timeout_ms = 100000\n", "");
+        let config: GenesisConfig = toml::from_str(&contents).unwrap();
+        assert_eq!(
+            config.consensus.activity_timeout_ms,
+            defaults::DEFAULT_ACTIVITY_TIMEOUT_MS
+        );
+    }
 }
\ No newline at end of file
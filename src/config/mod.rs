@@ -1,3 +1,4 @@
 pub mod validator;
 pub mod genesis;
-pub mod storage;
\ No newline at end of file
+pub mod storage;
+pub mod template;
\ No newline at end of file
@@ -3,6 +3,8 @@ use std::path::{Path, PathBuf};
 use std::fs;
 use std::env;
 
+use crate::config::template::DEFAULT_CONFIG_DIR;
+
 /// Error type for storage configuration operations
 #[derive(Debug)]
 pub enum ConfigError {
@@ -104,6 +106,9 @@ pub struct BackupConfig {
     pub retention_days: u32,
 }
 
+/// Commented default storage configuration written by `config init`
+const TEMPLATE: &str = include_str!("../../config/storage.toml");
+
 /// Default values for configuration parameters
 pub mod defaults {
     pub const BLOCKS_PER_SECTION: u64 = 1000;
@@ -117,9 +122,9 @@ pub mod defaults {
 }
 
 impl StorageConfig {
-    /// Loads the configuration from the default location
-    pub fn load_default() -> Result<Self, ConfigError> {
-        let config_path = Self::default_config_path()?;
+    /// Loads the configuration from `config_dir`, or the default location when `None`
+    pub fn load_default(config_dir: Option<&Path>) -> Result<Self, ConfigError> {
+        let config_path = Self::default_config_path(config_dir)?;
         Self::load(&config_path)
     }
 
@@ -131,15 +136,26 @@ impl StorageConfig {
         Ok(config)
     }
 
+    /// Writes the commented default storage configuration to `path`.
+    ///
+    /// Refuses to replace an existing file unless `force` is set.
+    pub fn write_template<P: AsRef<Path>>(path: P, force: bool) -> Result<(), ConfigError> {
+        crate::config::template::write_file(path.as_ref(), TEMPLATE, force)?;
+        Ok(())
+    }
+
     /// Determines the default configuration path
-    fn default_config_path() -> Result<PathBuf, ConfigError> {
-        // First check if path is specified in environment
-        if let Ok(path) = env::var("ROMER_STORAGE_CONFIG") {
-            return Ok(PathBuf::from(path));
+    fn default_config_path(config_dir: Option<&Path>) -> Result<PathBuf, ConfigError> {
+        // First check if path is specified in environment, unless a config
+        // directory was given explicitly
+        if config_dir.is_none() {
+            if let Ok(path) = env::var("ROMER_STORAGE_CONFIG") {
+                return Ok(PathBuf::from(path));
+            }
         }
 
         // Then check in the config directory
-        let config_dir = PathBuf::from("config");
+        let config_dir = config_dir.unwrap_or(Path::new(DEFAULT_CONFIG_DIR));
         
         // Check for environment-specific config first
         let env = env::var("ROMER_ENV").unwrap_or_else(|_| "development".to_string());
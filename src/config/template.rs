@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tracing::info;
+
+use crate::config::genesis::GenesisConfig;
+use crate::config::storage::StorageConfig;
+use crate::config::validator::ValidatorConfig;
+use crate::node::operating_regions::RegionConfig;
+
+/// Directory configuration is loaded from when `--config-dir` isn't given
+pub const DEFAULT_CONFIG_DIR: &str = "config";
+
+/// File names of the configuration files written by `config init`
+pub const GENESIS_FILE: &str = "genesis.toml";
+pub const STORAGE_FILE: &str = "storage.toml";
+pub const VALIDATOR_FILE: &str = "validator.toml";
+pub const REGIONS_FILE: &str = "regions.toml";
+
+/// Writes `contents` to `path`, refusing to replace an existing file unless `force` is set
+pub fn write_file(path: &Path, contents: &str, force: bool) -> io::Result<()> {
+    if path.exists() && !force {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!(
+                "{} already exists, use --force to overwrite",
+                path.display()
+            ),
+        ));
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Writes default genesis, storage, validator, and region configuration files into `dir`.
+///
+/// Without `force`, nothing is written if any of the files already exist.
+pub fn init_config_dir(dir: &Path, force: bool) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let paths: Vec<PathBuf> = [GENESIS_FILE, STORAGE_FILE, VALIDATOR_FILE, REGIONS_FILE]
+        .iter()
+        .map(|file| dir.join(file))
+        .collect();
+
+    if !force {
+        if let Some(existing) = paths.iter().find(|path| path.exists()) {
+            return Err(format!(
+                "{} already exists, use --force to overwrite",
+                existing.display()
+            )
+            .into());
+        }
+    }
+
+    GenesisConfig::write_template(&paths[0], force)?;
+    StorageConfig::write_template(&paths[1], force)?;
+    ValidatorConfig::write_template(&paths[2], force)?;
+    RegionConfig::write_template(&paths[3], force)?;
+
+    for path in &paths {
+        info!("Wrote {}", path.display());
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_config_dir() {
+        let dir = std::env::temp_dir().join("romer_test_config_init");
+        let _ = fs::remove_dir_all(&dir);
+
+        init_config_dir(&dir, false).unwrap();
+
+        // The generated files parse back through the loaders
+        GenesisConfig::load(dir.join(GENESIS_FILE)).unwrap();
+        StorageConfig::load(dir.join(STORAGE_FILE)).unwrap();
+        let validator: ValidatorConfig =
+            toml::from_str(&fs::read_to_string(dir.join(VALIDATOR_FILE)).unwrap()).unwrap();
+        let regions: RegionConfig =
+            toml::from_str(&fs::read_to_string(dir.join(REGIONS_FILE)).unwrap()).unwrap();
+        let city_key = validator.city.to_lowercase().replace(' ', "-");
+        assert!(regions.regions.city.contains_key(&city_key));
+
+        // Existing files are only replaced with force
+        assert!(init_config_dir(&dir, false).is_err());
+        init_config_dir(&dir, true).unwrap();
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_from_config_dir() {
+        let dir = std::env::temp_dir().join("romer_test_config_dir");
+        let _ = fs::remove_dir_all(&dir);
+        init_config_dir(&dir, false).unwrap();
+
+        GenesisConfig::load_default(Some(&dir)).unwrap();
+        StorageConfig::load_default(Some(&dir)).unwrap();
+        RegionConfig::load(Some(&dir)).unwrap();
+        let validator = ValidatorConfig::load_validator_config(Some(&dir)).unwrap();
+        assert_eq!(validator.city, "Amsterdam");
+
+        // A directory without configuration files is an error, not a fallback
+        let empty = dir.join("empty");
+        fs::create_dir_all(&empty).unwrap();
+        assert!(GenesisConfig::load_default(Some(&empty)).is_err());
+        assert!(StorageConfig::load_default(Some(&empty)).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::config::template::{DEFAULT_CONFIG_DIR, VALIDATOR_FILE};
 use crate::node::operating_regions::RegionConfig;
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -30,11 +31,29 @@ impl std::fmt::Display for ValidatorConfigError {
 
 impl std::error::Error for ValidatorConfigError {}
 
+/// Commented validator configuration written by `config init`
+const TEMPLATE: &str = r#"# Rømer Chain Validator Configuration
+
+# The city this validator operates from. Must match a city defined in regions.toml.
+city = "Amsterdam"
+"#;
+
 impl ValidatorConfig {
-    /// Loads the validator configuration from the config directory
-    pub fn load_validator_config() -> Result<Self, ValidatorConfigError> {
+    /// Writes a commented validator configuration template to `path`.
+    ///
+    /// Refuses to replace an existing file unless `force` is set.
+    pub fn write_template<P: AsRef<Path>>(
+        path: P,
+        force: bool,
+    ) -> Result<(), ValidatorConfigError> {
+        crate::config::template::write_file(path.as_ref(), TEMPLATE, force)
+            .map_err(ValidatorConfigError::IoError)
+    }
+
+    /// Loads the validator configuration from `config_dir`, or `./config` when `None`
+    pub fn load_validator_config(config_dir: Option<&Path>) -> Result<Self, ValidatorConfigError> {
         // Load region configuration first
-        let region_config = RegionConfig::load().map_err(|e| {
+        let region_config = RegionConfig::load(config_dir).map_err(|e| {
             ValidatorConfigError::IoError(std::io::Error::new(
                 std::io::ErrorKind::Other,
                 format!("Failed to load region config: {}", e),
@@ -42,7 +61,9 @@ impl ValidatorConfig {
         })?;
 
         // Load validator configuration
-        let config_path = PathBuf::from("config/validator.toml");
+        let config_path = config_dir
+            .unwrap_or(Path::new(DEFAULT_CONFIG_DIR))
+            .join(VALIDATOR_FILE);
         let contents =
             fs::read_to_string(config_path).map_err(|e| ValidatorConfigError::IoError(e))?;
 
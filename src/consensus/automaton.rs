@@ -1,4 +1,4 @@
-use bytes::{BufMut, Bytes, BytesMut};
+use bytes::Bytes;
 use commonware_consensus::{simplex::Context, Automaton};
 use commonware_consensus::{Committer, Relay, Supervisor};
 use commonware_cryptography::{Ed25519, PublicKey, Scheme};
@@ -6,13 +6,37 @@ use commonware_p2p::{Recipients, Sender};
 use commonware_runtime::deterministic::Context as RuntimeContext;
 use commonware_runtime::Clock;
 use futures::channel::oneshot;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime};
-use tracing::{info, warn};
+use thiserror::Error;
+use tracing::{debug, info, warn};
 
+use crate::block::merkle::merkle_root;
 use crate::block::{Block, BlockHeader};
 use crate::config::genesis::GenesisConfig;
 use crate::config::storage::StorageConfig;
+use crate::consensus::slashing::SlashingMonitor;
 use crate::consensus::supervisor::BlockchainSupervisor;
+use crate::node::validator::NodeRole;
+use crate::state::{StateError, StateStore};
+use crate::utils::hex::encode_hash;
+
+/// File name of the persisted genesis block within the genesis partition
+const GENESIS_FILE: &str = "genesis.block";
+
+/// Errors produced when applying a finalized block to local state
+#[derive(Error, Debug, PartialEq)]
+pub enum FinalizeError {
+    /// The finalized digest names a block this node has never seen
+    #[error("Finalized block {0} is not in the local block store")]
+    UnknownBlock(String),
+
+    #[error("Failed to apply finalized block: {0}")]
+    State(#[from] StateError),
+}
 
 /// Core blockchain automaton responsible for block creation, validation, and network interactions
 #[derive(Clone)]
@@ -22,7 +46,16 @@ pub struct BlockchainAutomaton {
     pub signer: Ed25519,
     genesis_config: GenesisConfig,
     storage_config: StorageConfig,
+    role: NodeRole,
     pub supervisor: BlockchainSupervisor,
+    /// Account balances after the most recently finalized block, shared by every
+    /// clone handed to the consensus engine
+    pub state: Arc<Mutex<StateStore>>,
+    /// Proposals seen in recent views, used to catch leaders that equivocate
+    slashing: Arc<Mutex<SlashingMonitor>>,
+    /// Blocks proposed locally or received from peers, keyed by hash. Consensus
+    /// only carries block hashes, so full blocks are looked up here.
+    pub blocks: Arc<Mutex<HashMap<[u8; 32], Block>>>,
 }
 
 impl BlockchainAutomaton {
@@ -31,174 +64,280 @@ impl BlockchainAutomaton {
         signer: Ed25519,
         genesis_config: GenesisConfig,
         storage_config: StorageConfig,
+        role: NodeRole,
     ) -> Self {
         // Clone the signer to create the supervisor
         let supervisor_signer = signer.clone();
+        let min_validators = genesis_config.consensus.min_validators as usize;
+        let genesis = build_genesis_block(&genesis_config);
 
-        Self {
+        let automaton = Self {
             runtime,
             p2p_sender: None,
             signer,
             genesis_config,
             storage_config,
-            supervisor: BlockchainSupervisor::new(supervisor_signer.public_key()),
-        }
+            role,
+            supervisor: BlockchainSupervisor::new(supervisor_signer.public_key(), min_validators),
+            state: Arc::new(Mutex::new(StateStore::new(&genesis))),
+            slashing: Arc::new(Mutex::new(SlashingMonitor::new())),
+            blocks: Arc::new(Mutex::new(HashMap::new())),
+        };
+        automaton.store_block(genesis);
+        automaton
     }
 
-    pub async fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
-        // Construct the full path to the genesis data directory
-        let genesis_path = self
-            .storage_config
+    pub async fn run(&self) -> Result<(), std::io::Error> {
+        self.initialize_genesis_state().await?;
+        Ok(())
+    }
+
+    /// Directory holding the persisted genesis block
+    fn genesis_path(&self) -> PathBuf {
+        self.storage_config
             .paths
             .data_dir
             .join(&self.storage_config.paths.journal_dir)
-            .join(&self.storage_config.journal.partitions.genesis);
-
-        // Check if the directory exists
-        match std::fs::read_dir(&genesis_path) {
-            Ok(mut entries) => {
-                // Check if the directory is empty
-                let is_empty = entries.next().is_none();
-
-                if is_empty {
-                    info!("Genesis data directory exists but is empty. Creating genesis block.");
-                    // Pass the genesis time from config
-                    let genesis_block = self
-                        .create_genesis_block(self.genesis_config.network.genesis_time)
-                        .await;
-
-                } else {
-                    info!("Genesis data already exists. Skipping genesis block creation.");
-                }
-            }
-            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
-                // Directory doesn't exist, create it and the genesis block
-                info!("Genesis data directory not found. Creating directory and genesis block.");
-                std::fs::create_dir_all(&genesis_path)?;
-
-                // Pass the genesis time from config
-                let genesis_block = self
-                    .create_genesis_block(self.genesis_config.network.genesis_time)
-                    .await;
+            .join(&self.storage_config.journal.partitions.genesis)
+    }
 
-                // TODO: Add code to store the genesis block
+    /// Persists the genesis block exactly once.
+    ///
+    /// Safe to call repeatedly: an existing valid genesis is left untouched, and a
+    /// torn or mismatched file from an interrupted attempt is replaced. The block is
+    /// written to a temporary file, synced, then renamed into place so readers never
+    /// observe a partial write.
+    pub async fn initialize_genesis_state(&self) -> Result<(), std::io::Error> {
+        let genesis_path = self.genesis_path();
+        std::fs::create_dir_all(&genesis_path)?;
+
+        let genesis_block = self.genesis_block();
+        let encoded = genesis_block.header.encode();
+
+        let genesis_file = genesis_path.join(GENESIS_FILE);
+        match std::fs::read(&genesis_file) {
+            Ok(existing) if existing == encoded.as_ref() => {
+                info!("Genesis data already exists. Skipping genesis block creation.");
+                return Ok(());
             }
-            Err(e) => {
-                // Some other error occurred
-                return Err(Box::new(e));
+            Ok(_) => {
+                warn!("Existing genesis data is incomplete or invalid. Rewriting genesis block.");
             }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                info!("Genesis data not found. Creating genesis block.");
+            }
+            Err(e) => return Err(e),
+        }
+
+        let temp_file = genesis_path.join(format!("{}.tmp", GENESIS_FILE));
+        {
+            let mut file = std::fs::File::create(&temp_file)?;
+            file.write_all(&encoded)?;
+            file.sync_all()?;
         }
+        std::fs::rename(&temp_file, &genesis_file)?;
 
         Ok(())
     }
 
+    /// Whether this node is the elected leader for the given view.
+    /// Observers never lead.
+    fn is_leader(&self, view: u64) -> bool {
+        self.role == NodeRole::Validator
+            && self.supervisor.leader(view, ()) == Some(self.signer.public_key())
+    }
+
+    /// Checks a proposed block against the consensus context: it must be stamped
+    /// with the view, name that view's leader as proposer, and extend the parent.
+    /// A second, different proposal from the same leader in the same view is
+    /// rejected as equivocation.
+    fn is_valid_proposal(&self, context: &Context, block: &Block) -> bool {
+        let header = &block.header;
+        if u64::from(header.view) != context.view {
+            warn!(
+                "Block view {} does not match view {}",
+                header.view, context.view
+            );
+            return false;
+        }
+        if self.supervisor.leader(context.view, ()) != Some(header.validator_public_key.clone()) {
+            warn!(
+                "Block at view {} was not proposed by the leader",
+                context.view
+            );
+            return false;
+        }
+
+        let Some(parent) = self.lookup_block(&context.parent.1) else {
+            warn!("Unknown parent for view {}", context.view);
+            return false;
+        };
+        if header.previous_hash != parent.hash() || header.height != parent.header.height + 1 {
+            warn!("Block at view {} does not extend its parent", context.view);
+            return false;
+        }
+
+        let max_drift = Duration::from_millis(self.genesis_config.consensus.max_block_drift_ms);
+        if let Err(e) = block.validate_with_clock(&parent, self.runtime.current(), max_drift) {
+            warn!("Invalid block at view {}: {}", context.view, e);
+            return false;
+        }
+
+        // A leader that proposes two different blocks for one view is equivocating.
+        // The evidence stays with the monitor until it is reported on finalization.
+        if self.slashing.lock().unwrap().record_proposal(
+            header.validator_public_key.clone(),
+            context.view,
+            block.hash(),
+        ) {
+            warn!(
+                "Leader proposed conflicting blocks at view {}",
+                context.view
+            );
+            return false;
+        }
+        true
+    }
+
+    /// Drains the equivocation evidence gathered so far and reports it
+    fn report_evidence(&self) {
+        for evidence in self.slashing.lock().unwrap().take_evidence() {
+            warn!(
+                "Validator {} proposed both {} and {} at view {}",
+                hex::encode(&evidence.validator),
+                encode_hash(&evidence.first_hash),
+                encode_hash(&evidence.second_hash),
+                evidence.view
+            );
+        }
+    }
+
     /// Set the P2P sender for network communication
     pub fn set_sender(&mut self, sender: commonware_p2p::authenticated::Sender) {
         self.p2p_sender = Some(sender);
     }
 
-    /// Create the initial genesis block for the blockchain
-    async fn create_genesis_block(&self, genesis_time: u64) -> Block {
-        Block {
-            header: BlockHeader {
-                view: 0,
-                height: 0,
-                timestamp: SystemTime::UNIX_EPOCH + Duration::from_secs(genesis_time),
-                previous_hash: [0; 32],
-                transactions_root: [0; 32],
-                state_root: [0; 32],
-                validator_public_key: self.signer.public_key(),
-                utilization: 0.0,
-            },
-            transactions: vec![],
-        }
+    /// The genesis block for the configured genesis time
+    fn genesis_block(&self) -> Block {
+        build_genesis_block(&self.genesis_config)
+    }
+
+    /// Adds a block to the local store and returns its hash
+    fn store_block(&self, block: Block) -> [u8; 32] {
+        let hash = block.hash();
+        self.blocks.lock().unwrap().insert(hash, block);
+        hash
+    }
+
+    /// Looks up the block behind a consensus digest
+    fn lookup_block(&self, digest: &[u8]) -> Option<Block> {
+        let hash: [u8; 32] = digest.try_into().ok()?;
+        self.blocks.lock().unwrap().get(&hash).cloned()
+    }
+
+    /// Applies the block behind a finalized digest to the account state and
+    /// checkpoints its height
+    fn apply_finalized(&self, digest: &[u8]) -> Result<Block, FinalizeError> {
+        let block = self
+            .lookup_block(digest)
+            .ok_or_else(|| FinalizeError::UnknownBlock(hex::encode(digest)))?;
+
+        let reward = self.genesis_config.consensus.block_reward;
+        let mut state = self.state.lock().unwrap();
+        state.apply_block(&block, reward)?;
+        state.checkpoint(block.header.height);
+        Ok(block)
     }
 }
 
+/// Builds the genesis block for the genesis time in `config`
+fn build_genesis_block(config: &GenesisConfig) -> Block {
+    Block::genesis(SystemTime::UNIX_EPOCH + Duration::from_secs(config.network.genesis_time))
+}
+
 // The rest of the trait implementations remain the same as in the previous version
 impl Automaton for BlockchainAutomaton {
     type Context = Context;
 
     async fn genesis(&mut self) -> Bytes {
-        // Create genesis block using the time from our config
-        let genesis_block = self
-            .create_genesis_block(self.genesis_config.network.genesis_time)
-            .await;
-
-        let mut buffer = BytesMut::new();
-
-        // Serialize the block data
-        buffer.put_u32(genesis_block.header.view);
-        buffer.put_u64(genesis_block.header.height);
-
-        // Convert SystemTime to u64 timestamp
-        let timestamp = genesis_block
-            .header
-            .timestamp
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        buffer.put_u64(timestamp);
-
-        buffer.put_slice(&genesis_block.header.previous_hash);
-        buffer.put_slice(&genesis_block.header.transactions_root);
-        buffer.put_slice(&genesis_block.header.state_root);
-        buffer.put_slice(&genesis_block.header.validator_public_key);
-        buffer.put_f64(genesis_block.header.utilization);
-
-        buffer.freeze()
+        // Create genesis block using the time from our config. Its digest is the
+        // block hash, the same value the first block stores as previous_hash.
+        let genesis_block = self.genesis_block();
+
+        Bytes::copy_from_slice(&genesis_block.hash())
     }
     // Changed to return the Future directly instead of nesting it
     async fn propose(&mut self, context: Self::Context) -> oneshot::Receiver<Bytes> {
-        let timestamp: u64 = self
-            .runtime
-            .current()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let block = Bytes::from(format!("Block at view {}: {}", context.view, timestamp));
+        let (tx, rx) = oneshot::channel();
 
-        if let Some(sender) = &mut self.p2p_sender {
-            if let Err(e) = sender.send(Recipients::All, block.clone(), true).await {
-                warn!("Failed to broadcast block: {}", e);
-            }
+        // Only the elected leader for this view builds and broadcasts a block.
+        // Dropping the sender tells consensus we have no proposal.
+        if !self.is_leader(context.view) {
+            debug!(
+                "Not the leader for view {}, skipping proposal",
+                context.view
+            );
+            return rx;
         }
 
-        // Create and return the receiver directly
-        let (tx, rx) = oneshot::channel();
-        let _ = tx.send(block);
+        let Some(parent) = self.lookup_block(&context.parent.1) else {
+            warn!(
+                "Unknown parent for view {}, skipping proposal",
+                context.view
+            );
+            return rx;
+        };
+
+        // Never stamp a block earlier than its parent
+        let timestamp = self.runtime.current().max(parent.header.timestamp);
+        let transactions = vec![];
+        let block = Block {
+            header: BlockHeader {
+                view: context.view as u32,
+                height: parent.header.height + 1,
+                timestamp,
+                previous_hash: parent.hash(),
+                transactions_root: merkle_root(&transactions),
+                state_root: [0; 32],
+                validator_public_key: self.signer.public_key(),
+                utilization: 0.0,
+            },
+            transactions,
+        };
+
+        // Consensus agrees on the hash; Relay::broadcast ships the full block
+        let hash = self.store_block(block);
+        let _ = tx.send(Bytes::copy_from_slice(&hash));
         rx
     }
 
     // Changed to return the Future directly instead of nesting it
     async fn verify(&mut self, context: Self::Context, payload: Bytes) -> oneshot::Receiver<bool> {
-        let is_valid = if payload.is_empty() {
-            warn!("Received empty payload at view {}", context.view);
-            false
-        } else {
-            match String::from_utf8(payload.to_vec()) {
-                Ok(block_content) => {
-                    let is_valid = block_content.contains(&format!("view {}", context.view));
-                    if is_valid {
-                        if let Some(sender) = &mut self.p2p_sender {
-                            let validation_message = Bytes::from(format!(
-                                "Block validated for view {}: {}",
-                                context.view, block_content
-                            ));
-                            if let Err(e) =
-                                sender.send(Recipients::All, validation_message, true).await
-                            {
-                                warn!("Failed to broadcast validation: {}", e);
-                            }
+        let is_valid = match self.lookup_block(&payload) {
+            Some(block) => {
+                let is_valid = self.is_valid_proposal(&context, &block);
+                if is_valid {
+                    if let Some(sender) = &mut self.p2p_sender {
+                        let validation_message = Bytes::from(format!(
+                            "Block validated for view {}: {}",
+                            context.view,
+                            encode_hash(&block.hash())
+                        ));
+                        if let Err(e) = sender.send(Recipients::All, validation_message, true).await
+                        {
+                            warn!("Failed to broadcast validation: {}", e);
                         }
                     }
-                    is_valid
-                }
-                Err(_) => {
-                    warn!("Invalid UTF-8 payload at view {}", context.view);
-                    false
                 }
+                is_valid
+            }
+            None => {
+                warn!(
+                    "Unknown block {} at view {}",
+                    hex::encode(&payload),
+                    context.view
+                );
+                false
             }
         };
 
@@ -210,10 +349,23 @@ impl Automaton for BlockchainAutomaton {
 }
 
 impl Relay for BlockchainAutomaton {
+    /// Sends peers the full block behind a proposed digest
     async fn broadcast(&mut self, payload: Bytes) {
+        let Some(block) = self.lookup_block(&payload) else {
+            warn!("Cannot broadcast unknown block {}", hex::encode(&payload));
+            return;
+        };
+        let message = match block.to_json() {
+            Ok(json) => Bytes::from(json),
+            Err(e) => {
+                warn!("Failed to encode block for broadcast: {}", e);
+                return;
+            }
+        };
+
         if let Some(sender) = &mut self.p2p_sender {
             let mut sender = sender.clone();
-            if let Err(e) = sender.send(Recipients::All, payload, true).await {
+            if let Err(e) = sender.send(Recipients::All, message, true).await {
                 warn!("Failed to broadcast: {}", e);
             }
         }
@@ -222,11 +374,27 @@ impl Relay for BlockchainAutomaton {
 
 impl Committer for BlockchainAutomaton {
     async fn prepared(&mut self, _proof: Bytes, payload: Bytes) {
-        info!("Block prepared: {:?}", String::from_utf8_lossy(&payload));
+        info!("Block prepared: {}", hex::encode(&payload));
     }
 
+    /// Applies the finalized block to local state.
+    ///
+    /// Consensus has already committed the block, so a node that can't apply it
+    /// would silently drift from the chain. It stops instead.
     async fn finalized(&mut self, _proof: Bytes, payload: Bytes) {
-        info!("Block finalized: {:?}", String::from_utf8_lossy(&payload));
+        let block = self
+            .apply_finalized(&payload)
+            .unwrap_or_else(|e| panic!("Cannot follow the finalized chain: {}", e));
+
+        // Report equivocation before the views it happened in are pruned
+        self.report_evidence();
+
+        // Views older than the activity timeout can no longer be voted on
+        let horizon = u64::from(block.header.view)
+            .saturating_sub(self.genesis_config.consensus.activity_timeout_views());
+        self.slashing.lock().unwrap().prune_below(horizon);
+
+        info!("Block finalized: {}", encode_hash(&block.hash()));
     }
 }
 
@@ -234,17 +402,358 @@ impl Supervisor for BlockchainAutomaton {
     type Index = u64;
     type Seed = ();
 
-    fn leader(&self, _index: Self::Index, _seed: Self::Seed) -> Option<PublicKey> {
-        Some(self.signer.public_key())
+    // Delegate to the BlockchainSupervisor so both implementations share a single,
+    // sorted view of the participant set
+
+    fn leader(&self, index: Self::Index, seed: Self::Seed) -> Option<PublicKey> {
+        self.supervisor.leader(index, seed)
+    }
+
+    fn participants(&self, index: Self::Index) -> Option<&Vec<PublicKey>> {
+        self.supervisor.participants(index)
     }
 
-    fn participants(&self, _index: Self::Index) -> Option<&Vec<PublicKey>> {
-        None
+    fn is_participant(&self, index: Self::Index, candidate: &PublicKey) -> Option<u32> {
+        self.supervisor.is_participant(index, candidate)
     }
 
-    fn is_participant(&self, _index: Self::Index, _candidate: &PublicKey) -> Option<u32> {
-        Some(0)
+    async fn report(&self, activity: u8, proof: Bytes) {
+        self.supervisor.report(activity, proof).await
     }
+}
 
-    async fn report(&self, _activity: u8, _proof: Bytes) {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus::slashing::SlashingEvidence;
+    use crate::utils::base58;
+    use commonware_runtime::{deterministic::Executor, Runner};
+
+    /// Moves the deterministic clock, which starts at the Unix epoch, to genesis
+    async fn advance_to_genesis(automaton: &BlockchainAutomaton) {
+        let genesis_time = automaton.genesis_block().header.timestamp;
+        automaton.runtime.sleep_until(genesis_time).await;
+    }
+
+    #[test]
+    fn test_initialize_genesis_state_is_idempotent() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let data_dir = std::env::temp_dir().join("romer_test_genesis_idempotent");
+            let _ = std::fs::remove_dir_all(&data_dir);
+
+            let mut storage_config = StorageConfig::development();
+            storage_config.paths.data_dir = data_dir.clone();
+
+            let automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                storage_config,
+                NodeRole::Validator,
+            );
+
+            automaton.initialize_genesis_state().await.unwrap();
+            automaton.initialize_genesis_state().await.unwrap();
+
+            let entries: Vec<_> = std::fs::read_dir(automaton.genesis_path())
+                .unwrap()
+                .collect();
+            assert_eq!(entries.len(), 1);
+
+            std::fs::remove_dir_all(&data_dir).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_genesis_digest_is_block_hash() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let data_dir = std::env::temp_dir().join("romer_test_genesis_digest");
+            let _ = std::fs::remove_dir_all(&data_dir);
+
+            let mut storage_config = StorageConfig::development();
+            storage_config.paths.data_dir = data_dir.clone();
+
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                storage_config,
+                NodeRole::Validator,
+            );
+            automaton.initialize_genesis_state().await.unwrap();
+            let digest = automaton.genesis().await;
+
+            // The digest commits to the stored genesis header
+            let stored = std::fs::read(automaton.genesis_path().join(GENESIS_FILE)).unwrap();
+            assert_eq!(digest, commonware_utils::hash(&stored));
+
+            // A block built on the digest links back to the genesis block
+            let genesis_block = automaton.genesis_block();
+            let mut header = genesis_block.header.clone();
+            header.view = 1;
+            header.height = 1;
+            header.previous_hash.copy_from_slice(&digest);
+            let child = Block {
+                header,
+                transactions: vec![],
+            };
+            let get_block =
+                |hash: &[u8; 32]| (*hash == genesis_block.hash()).then(|| genesis_block.clone());
+            assert!(genesis_block.is_ancestor_of(&child, get_block));
+
+            std::fs::remove_dir_all(&data_dir).unwrap();
+        });
+    }
+
+    /// Asserts that a supervisor reports a consistent participant set
+    fn assert_consistent_participants<S: Supervisor<Index = u64>>(supervisor: &S) {
+        let participants = supervisor.participants(0).unwrap().clone();
+        let mut sorted = participants.clone();
+        sorted.sort();
+        assert_eq!(participants, sorted);
+
+        for (index, participant) in participants.iter().enumerate() {
+            assert_eq!(
+                supervisor.is_participant(0, participant),
+                Some(index as u32)
+            );
+        }
+    }
+
+    #[test]
+    fn test_supervisor_implementations_agree() {
+        let (_, runtime, _) = Executor::default();
+        let mut automaton = BlockchainAutomaton::new(
+            runtime,
+            Ed25519::from_seed(0),
+            GenesisConfig::development(),
+            StorageConfig::development(),
+            NodeRole::Validator,
+        );
+        automaton.supervisor.update_validators(
+            (0..3)
+                .map(|seed| Ed25519::from_seed(seed).public_key())
+                .collect(),
+        );
+
+        assert_consistent_participants(&automaton);
+        assert_consistent_participants(&automaton.supervisor);
+        assert_eq!(
+            automaton.participants(0),
+            automaton.supervisor.participants(0)
+        );
+    }
+
+    #[test]
+    fn test_propose_only_when_leader() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                StorageConfig::development(),
+                NodeRole::Validator,
+            );
+            let genesis = automaton.genesis().await;
+            let context = || Context {
+                view: 1,
+                parent: (0, genesis.clone()),
+            };
+
+            // The node is the leader and proposes a block
+            let proposal = automaton.propose(context()).await;
+            assert!(proposal.await.is_ok());
+
+            // Another validator leads the view, so the node stays quiet
+            automaton.supervisor = BlockchainSupervisor::new(Ed25519::from_seed(1).public_key(), 1);
+            let proposal = automaton.propose(context()).await;
+            assert!(proposal.await.is_err());
+        });
+    }
+
+    #[test]
+    fn test_finalized_block_updates_state() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let mut genesis_config = GenesisConfig::development();
+            genesis_config.consensus.block_reward = 5;
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                genesis_config,
+                StorageConfig::development(),
+                NodeRole::Validator,
+            );
+            advance_to_genesis(&automaton).await;
+            let genesis = automaton.genesis().await;
+            let context = |view| Context {
+                view,
+                parent: (0, genesis.clone()),
+            };
+
+            let payload = automaton.propose(context(1)).await.await.unwrap();
+            assert_eq!(payload.len(), 32);
+            let block = automaton.lookup_block(&payload).unwrap();
+            assert_eq!(block.header.height, 1);
+            assert_eq!(block.header.previous_hash, automaton.genesis_block().hash());
+
+            // The proposal only verifies in the view it was built for
+            assert!(automaton
+                .verify(context(1), payload.clone())
+                .await
+                .await
+                .unwrap());
+            assert!(!automaton
+                .verify(context(2), payload.clone())
+                .await
+                .await
+                .unwrap());
+
+            // Finalizing pays the proposer and checkpoints the new height
+            automaton.finalized(Bytes::new(), payload).await;
+            let mut state = automaton.state.lock().unwrap();
+            let proposer = base58::address(&automaton.signer.public_key());
+            assert_eq!(state.get_balance(&proposer), 5);
+            assert!(state.rollback_to(1).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_apply_finalized_rejects_unknown_block() {
+        let (_, runtime, _) = Executor::default();
+        let automaton = BlockchainAutomaton::new(
+            runtime,
+            Ed25519::from_seed(0),
+            GenesisConfig::development(),
+            StorageConfig::development(),
+            NodeRole::Validator,
+        );
+
+        assert_eq!(
+            automaton.apply_finalized(&[7; 32]).unwrap_err(),
+            FinalizeError::UnknownBlock(hex::encode([7; 32]))
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_equivocation() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                StorageConfig::development(),
+                NodeRole::Validator,
+            );
+            advance_to_genesis(&automaton).await;
+            let genesis = automaton.genesis().await;
+            let context = || Context {
+                view: 1,
+                parent: (0, genesis.clone()),
+            };
+
+            let payload = automaton.propose(context()).await.await.unwrap();
+            assert!(automaton
+                .verify(context(), payload.clone())
+                .await
+                .await
+                .unwrap());
+
+            // Seeing the same proposal again is fine
+            assert!(automaton
+                .verify(context(), payload.clone())
+                .await
+                .await
+                .unwrap());
+
+            // A different block from the same leader in the same view is not
+            let mut block = automaton.lookup_block(&payload).unwrap();
+            block.header.utilization = 0.5;
+            let conflicting_hash = automaton.store_block(block);
+            let conflicting = Bytes::copy_from_slice(&conflicting_hash);
+            assert!(!automaton
+                .verify(context(), conflicting)
+                .await
+                .await
+                .unwrap());
+
+            // Rejecting the block leaves the evidence for the slashing pipeline
+            let evidence = automaton.slashing.lock().unwrap().take_evidence();
+            assert_eq!(
+                evidence,
+                vec![SlashingEvidence {
+                    validator: automaton.signer.public_key(),
+                    view: 1,
+                    first_hash: payload.as_ref().try_into().unwrap(),
+                    second_hash: conflicting_hash,
+                }]
+            );
+        });
+    }
+
+    #[test]
+    fn test_verify_rejects_future_block() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                StorageConfig::development(),
+                NodeRole::Validator,
+            );
+            advance_to_genesis(&automaton).await;
+            let genesis = automaton.genesis().await;
+            let context = || Context {
+                view: 1,
+                parent: (0, genesis.clone()),
+            };
+            let payload = automaton.propose(context()).await.await.unwrap();
+
+            // A block stamped beyond the allowed drift is rejected
+            let max_drift =
+                Duration::from_millis(automaton.genesis_config.consensus.max_block_drift_ms);
+            let mut block = automaton.lookup_block(&payload).unwrap();
+            block.header.timestamp =
+                automaton.runtime.current() + max_drift + Duration::from_secs(1);
+            let future = Bytes::copy_from_slice(&automaton.store_block(block));
+            assert!(!automaton.verify(context(), future).await.await.unwrap());
+
+            // The block stamped with the current time is still accepted
+            assert!(automaton.verify(context(), payload).await.await.unwrap());
+        });
+    }
+
+    #[test]
+    fn test_observer_never_proposes() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            let mut automaton = BlockchainAutomaton::new(
+                runtime,
+                Ed25519::from_seed(0),
+                GenesisConfig::development(),
+                StorageConfig::development(),
+                NodeRole::Observer,
+            );
+
+            // The observer is the only participant but still stays quiet
+            for view in 1..=3 {
+                assert_eq!(
+                    automaton.supervisor.leader(view, ()),
+                    Some(automaton.signer.public_key())
+                );
+                let proposal = automaton
+                    .propose(Context {
+                        view,
+                        parent: (view - 1, Bytes::new()),
+                    })
+                    .await;
+                assert!(proposal.await.is_err());
+            }
+        });
+    }
 }
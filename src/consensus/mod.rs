@@ -1,2 +1,3 @@
 pub mod automaton;
+pub mod slashing;
 pub mod supervisor;
\ No newline at end of file
@@ -0,0 +1,116 @@
+use commonware_cryptography::PublicKey;
+use std::collections::{HashMap, HashSet};
+
+/// Proof that a validator proposed two different blocks in the same view
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlashingEvidence {
+    pub validator: PublicKey,
+    pub view: u64,
+    pub first_hash: [u8; 32],
+    pub second_hash: [u8; 32],
+}
+
+/// Detects validators that equivocate by proposing conflicting blocks for one view
+#[derive(Debug, Default)]
+pub struct SlashingMonitor {
+    /// First block hash seen from each validator in each view
+    proposals: HashMap<(PublicKey, u64), [u8; 32]>,
+    /// (validator, view) pairs already reported, so each offence yields one piece of evidence
+    flagged: HashSet<(PublicKey, u64)>,
+    evidence: Vec<SlashingEvidence>,
+}
+
+impl SlashingMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a proposal, returning true if it conflicts with an earlier proposal
+    /// from the same validator in the same view.
+    ///
+    /// Seeing the same block again is not an offence.
+    pub fn record_proposal(
+        &mut self,
+        validator: PublicKey,
+        view: u64,
+        block_hash: [u8; 32],
+    ) -> bool {
+        let key = (validator, view);
+        let first_hash = match self.proposals.get(&key) {
+            Some(first_hash) => *first_hash,
+            None => {
+                self.proposals.insert(key, block_hash);
+                return false;
+            }
+        };
+        if first_hash == block_hash {
+            return false;
+        }
+
+        if self.flagged.insert(key.clone()) {
+            self.evidence.push(SlashingEvidence {
+                validator: key.0,
+                view,
+                first_hash,
+                second_hash: block_hash,
+            });
+        }
+        true
+    }
+
+    /// Removes and returns the evidence gathered since the last call
+    pub fn take_evidence(&mut self) -> Vec<SlashingEvidence> {
+        std::mem::take(&mut self.evidence)
+    }
+
+    /// Forgets proposals from views before `view`, bounding memory as consensus advances
+    pub fn prune_below(&mut self, view: u64) {
+        self.proposals.retain(|(_, seen), _| *seen >= view);
+        self.flagged.retain(|(_, seen)| *seen >= view);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commonware_cryptography::{Ed25519, Scheme};
+
+    #[test]
+    fn test_conflicting_proposals_produce_evidence() {
+        let mut monitor = SlashingMonitor::new();
+        let validator = Ed25519::from_seed(0).public_key();
+
+        assert!(!monitor.record_proposal(validator.clone(), 7, [1; 32]));
+        assert!(monitor.record_proposal(validator.clone(), 7, [2; 32]));
+        assert!(monitor.record_proposal(validator.clone(), 7, [3; 32]));
+
+        assert_eq!(
+            monitor.take_evidence(),
+            vec![SlashingEvidence {
+                validator,
+                view: 7,
+                first_hash: [1; 32],
+                second_hash: [2; 32],
+            }]
+        );
+        assert!(monitor.take_evidence().is_empty());
+    }
+
+    #[test]
+    fn test_honest_proposals_produce_no_evidence() {
+        let mut monitor = SlashingMonitor::new();
+        let a = Ed25519::from_seed(0).public_key();
+        let b = Ed25519::from_seed(1).public_key();
+
+        assert!(!monitor.record_proposal(a.clone(), 1, [1; 32]));
+        assert!(!monitor.record_proposal(a.clone(), 1, [1; 32]));
+        assert!(!monitor.record_proposal(a.clone(), 2, [2; 32]));
+        assert!(!monitor.record_proposal(b, 1, [3; 32]));
+        assert!(monitor.take_evidence().is_empty());
+
+        // Pruned views start fresh
+        monitor.prune_below(2);
+        assert!(!monitor.record_proposal(a, 1, [4; 32]));
+        assert!(monitor.take_evidence().is_empty());
+    }
+}
@@ -1,39 +1,65 @@
 use commonware_cryptography::{PublicKey};
 use bytes::Bytes;
 use commonware_consensus::Supervisor;
-use tracing::info;
+use tracing::{info, warn};
 
 /// BlockchainSupervisor manages validator participation and leader selection
 /// for the consensus process. It ensures proper coordination of validators
 /// across different views of consensus.
 #[derive(Clone)]
 pub struct BlockchainSupervisor {
-    // Store the validator's own public key for leader selection
-    pub validator_key: PublicKey,
     // Track the current set of active validators
     active_validators: Vec<PublicKey>,
+    // Consensus stalls until at least this many distinct validators are active
+    min_validators: usize,
 }
 
 impl BlockchainSupervisor {
-    pub fn new(validator_key: PublicKey) -> Self {
-        Self {
-            validator_key: validator_key.clone(),
+    pub fn new(validator_key: PublicKey, min_validators: usize) -> Self {
+        let supervisor = Self {
             active_validators: vec![validator_key], // Start with self as only validator
+            min_validators,
+        };
+        supervisor.warn_if_below_quorum();
+        supervisor
+    }
+
+    /// Whether enough distinct validators are active for consensus to make progress
+    pub fn has_quorum(&self) -> bool {
+        self.active_validators.len() >= self.min_validators
+    }
+
+    fn warn_if_below_quorum(&self) {
+        if !self.has_quorum() {
+            warn!(
+                "Below validator quorum: {} of {} required validators active, consensus is stalled",
+                self.active_validators.len(),
+                self.min_validators
+            );
         }
     }
 
     /// Updates the set of active validators
-    pub fn update_validators(&mut self, validators: Vec<PublicKey>) {
+    ///
+    /// The set is kept sorted and free of duplicates, as consensus requires
+    /// every node to agree on participant ordering.
+    pub fn update_validators(&mut self, mut validators: Vec<PublicKey>) {
+        validators.sort();
+        validators.dedup();
         self.active_validators = validators;
         info!(
             "Updated active validator set. Count: {}",
             self.active_validators.len()
         );
+        self.warn_if_below_quorum();
     }
 
-    /// Internal helper to determine if a validator is active
-    fn is_active_validator(&self, candidate: &PublicKey) -> bool {
-        self.active_validators.contains(candidate)
+    /// Internal helper to find a validator's index in the sorted active set
+    fn validator_index(&self, candidate: &PublicKey) -> Option<u32> {
+        self.active_validators
+            .binary_search(candidate)
+            .ok()
+            .map(|index| index as u32)
     }
 }
 
@@ -41,26 +67,36 @@ impl Supervisor for BlockchainSupervisor {
     type Index = u64;  // View number type
     type Seed = ();    // No additional randomness needed yet
 
-    fn leader(&self, _index: Self::Index, _seed: Self::Seed) -> Option<PublicKey> {
-        // For now, always return self as leader
-        // In the future, implement proper leader rotation based on view number
-        Some(self.validator_key.clone())
+    fn leader(&self, index: Self::Index, _seed: Self::Seed) -> Option<PublicKey> {
+        // No leader, and so no progress, until the validator quorum is met. An
+        // empty set never has a leader, even if the quorum is misconfigured.
+        if !self.has_quorum() || self.active_validators.is_empty() {
+            return None;
+        }
+
+        // Rotate through the sorted validator set by view number. The leader is a
+        // pure function of the view, so nodes agree on it even after a restart.
+        let position = (index % self.active_validators.len() as u64) as usize;
+        Some(self.active_validators[position].clone())
     }
 
     fn participants(&self, _index: Self::Index) -> Option<&Vec<PublicKey>> {
+        if !self.has_quorum() {
+            return None;
+        }
+
         // Return the current set of active validators
         Some(&self.active_validators)
     }
 
     fn is_participant(&self, _index: Self::Index, candidate: &PublicKey) -> Option<u32> {
-        // Check if the candidate is an active validator
-        if self.is_active_validator(candidate) {
-            // Return 0 as the validator index for now
-            // In the future, implement proper validator indexing
-            Some(0)
-        } else {
-            None
+        // Below quorum there are no participants, matching participants()
+        if !self.has_quorum() {
+            return None;
         }
+
+        // Index of the candidate within the sorted active validator set
+        self.validator_index(candidate)
     }
 
     async fn report(&self, _activity: u8, _proof: Bytes) {
@@ -68,4 +104,84 @@ impl Supervisor for BlockchainSupervisor {
         // This will be important for implementing validator scoring
         // and performance tracking in the future
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commonware_cryptography::{Ed25519, Scheme};
+
+    #[test]
+    fn test_participants_sorted_and_deduplicated() {
+        let keys: Vec<PublicKey> = (0..4)
+            .map(|seed| Ed25519::from_seed(seed).public_key())
+            .collect();
+        let mut supervisor = BlockchainSupervisor::new(keys[0].clone(), 1);
+
+        let mut validators = keys.clone();
+        validators.push(keys[2].clone());
+        validators.reverse();
+        supervisor.update_validators(validators);
+
+        let mut expected = keys.clone();
+        expected.sort();
+        assert_eq!(supervisor.participants(0), Some(&expected));
+
+        for (index, key) in expected.iter().enumerate() {
+            assert_eq!(supervisor.is_participant(0, key), Some(index as u32));
+        }
+        assert_eq!(
+            supervisor.is_participant(0, &Ed25519::from_seed(99).public_key()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stalls_below_quorum() {
+        let keys: Vec<PublicKey> = (0..3)
+            .map(|seed| Ed25519::from_seed(seed).public_key())
+            .collect();
+        let mut supervisor = BlockchainSupervisor::new(keys[0].clone(), 3);
+        assert_eq!(supervisor.leader(1, ()), None);
+        assert_eq!(supervisor.participants(1), None);
+        assert_eq!(supervisor.is_participant(1, &keys[0]), None);
+
+        // Duplicates don't count towards the quorum
+        supervisor.update_validators(vec![keys[0].clone(), keys[1].clone(), keys[1].clone()]);
+        assert_eq!(supervisor.leader(1, ()), None);
+
+        supervisor.update_validators(keys.clone());
+        assert!(supervisor.leader(1, ()).is_some());
+        assert_eq!(supervisor.participants(1).map(|p| p.len()), Some(3));
+        assert!(supervisor.is_participant(1, &keys[0]).is_some());
+    }
+
+    #[test]
+    fn test_empty_set_has_no_leader() {
+        let mut supervisor = BlockchainSupervisor::new(Ed25519::from_seed(0).public_key(), 0);
+        supervisor.update_validators(Vec::new());
+
+        assert_eq!(supervisor.leader(1, ()), None);
+    }
+
+    #[test]
+    fn test_leader_rotates_by_view() {
+        let keys: Vec<PublicKey> = (0..4)
+            .map(|seed| Ed25519::from_seed(seed).public_key())
+            .collect();
+
+        // Fresh supervisors on different nodes, given the validators in different orders
+        let mut first = BlockchainSupervisor::new(keys[0].clone(), 1);
+        first.update_validators(keys.clone());
+        let mut second = BlockchainSupervisor::new(keys[3].clone(), 1);
+        second.update_validators(keys.iter().rev().cloned().collect());
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        for view in 0..10 {
+            let leader = first.leader(view, ());
+            assert_eq!(leader, second.leader(view, ()));
+            assert_eq!(leader, Some(sorted[view as usize % sorted.len()].clone()));
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use std::fs;
 use std::path::PathBuf;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
+use argon2::Argon2;
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
 use commonware_cryptography::{Ed25519, PrivateKey, Scheme};
 use rand::rngs::OsRng;
+use rand::RngCore;
 use thiserror::Error;
+use zeroize::Zeroizing;
 
 // Import the hardware detector for OS detection
 use crate::node::hardware_validator::{HardwareDetector, OperatingSystem};
@@ -23,6 +28,107 @@ pub enum KeyManagementError {
     /// Represents errors in home directory or user profile detection
     #[error("Directory access error: {0}")]
     DirectoryAccess(String),
+
+    /// Represents a key name that can't be used in a file name
+    #[error("Invalid key name: {0}")]
+    InvalidKeyName(String),
+
+    /// Represents a key file that would be overwritten
+    #[error("Key file {0:?} already exists, use --force to replace it")]
+    AlreadyExists(PathBuf),
+
+    /// Represents a missing, empty or mistyped key passphrase
+    #[error("Passphrase error: {0}")]
+    Passphrase(String),
+}
+
+/// Environment variable holding the passphrase used to encrypt the node key
+pub const KEY_PASSWORD_ENV: &str = "ROMER_KEY_PASSWORD";
+
+/// Environment variable overriding the directory keys are stored in
+pub const ROMER_HOME_ENV: &str = "ROMER_HOME";
+
+/// File name of the default, unnamed node key
+const DEFAULT_KEY_FILE: &str = "node.key";
+
+/// Prefix identifying an encrypted key file, followed by a format version byte
+const ENCRYPTED_KEY_MAGIC: &[u8] = b"ROMERKEY";
+const ENCRYPTED_KEY_VERSION: u8 = 1;
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const HEADER_LEN: usize = ENCRYPTED_KEY_MAGIC.len() + 1 + SALT_LEN + NONCE_LEN;
+
+/// Returns true if the key file contents carry the encrypted key header
+pub fn is_encrypted(contents: &[u8]) -> bool {
+    contents.starts_with(ENCRYPTED_KEY_MAGIC)
+}
+
+/// Encrypts private key bytes with a passphrase.
+///
+/// The output is the magic prefix, a version byte, the Argon2 salt and the
+/// XChaCha20-Poly1305 nonce, followed by the ciphertext.
+pub fn encrypt_key(private_key: &[u8], password: &str) -> Result<Vec<u8>, KeyManagementError> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let cipher = XChaCha20Poly1305::new((&*derive_key(password, &salt)?).into());
+    let ciphertext = cipher
+        .encrypt(XNonce::from_slice(&nonce), private_key)
+        .map_err(|_| KeyManagementError::Crypto("Failed to encrypt key".to_string()))?;
+
+    let mut contents = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+    contents.extend_from_slice(ENCRYPTED_KEY_MAGIC);
+    contents.push(ENCRYPTED_KEY_VERSION);
+    contents.extend_from_slice(&salt);
+    contents.extend_from_slice(&nonce);
+    contents.extend_from_slice(&ciphertext);
+    Ok(contents)
+}
+
+/// Decrypts a key file produced by `encrypt_key`
+pub fn decrypt_key(
+    contents: &[u8],
+    password: &str,
+) -> Result<Zeroizing<Vec<u8>>, KeyManagementError> {
+    if !is_encrypted(contents) || contents.len() <= HEADER_LEN {
+        return Err(KeyManagementError::Crypto(
+            "Malformed encrypted key file".to_string(),
+        ));
+    }
+
+    let version = contents[ENCRYPTED_KEY_MAGIC.len()];
+    if version != ENCRYPTED_KEY_VERSION {
+        return Err(KeyManagementError::Crypto(format!(
+            "Unsupported key file version: {}",
+            version
+        )));
+    }
+
+    let salt_start = ENCRYPTED_KEY_MAGIC.len() + 1;
+    let nonce_start = salt_start + SALT_LEN;
+    let salt = &contents[salt_start..nonce_start];
+    let nonce = &contents[nonce_start..HEADER_LEN];
+
+    let cipher = XChaCha20Poly1305::new((&*derive_key(password, salt)?).into());
+    cipher
+        .decrypt(XNonce::from_slice(nonce), &contents[HEADER_LEN..])
+        .map(Zeroizing::new)
+        .map_err(|_| {
+            KeyManagementError::Crypto(
+                "Failed to decrypt key: wrong passphrase or corrupted file".to_string(),
+            )
+        })
+}
+
+/// Derives a 256-bit encryption key from a passphrase with Argon2id
+fn derive_key(password: &str, salt: &[u8]) -> Result<Zeroizing<[u8; 32]>, KeyManagementError> {
+    let mut key = Zeroizing::new([0u8; 32]);
+    Argon2::default()
+        .hash_password_into(password.as_bytes(), salt, key.as_mut())
+        .map_err(|e| KeyManagementError::Crypto(format!("Key derivation failed: {}", e)))?;
+    Ok(key)
 }
 
 /// Manages node key generation, storage, and retrieval across different platforms
@@ -32,14 +138,55 @@ pub struct NodeKeyManager {
 
     /// Detected operating system to enable platform-specific handling
     os: OperatingSystem,
+
+    /// Passphrase for the key file, taken from ROMER_KEY_PASSWORD when set
+    password: Option<Zeroizing<String>>,
 }
 
 impl NodeKeyManager {
-    /// Creates a new NodeKeyManager, detecting the appropriate key storage location
-    /// based on the current operating system
+    /// Creates a new NodeKeyManager for the default `node.key`, detecting the
+    /// appropriate key storage location based on the current operating system
     pub fn new() -> Result<Self, KeyManagementError> {
-        // Detect the current operating system
         let os = HardwareDetector::detect_os();
+        let key_dir = Self::key_dir(&os)?;
+        Self::with_dir(key_dir, os, None)
+    }
+
+    /// Creates a NodeKeyManager for a named key stored as `node-<name>.key`,
+    /// so several validators can share one host
+    pub fn new_named(name: &str) -> Result<Self, KeyManagementError> {
+        let os = HardwareDetector::detect_os();
+        let key_dir = Self::key_dir(&os)?;
+        Self::with_dir(key_dir, os, Some(name))
+    }
+
+    /// Builds a key manager for a key in `key_dir`, creating the directory if needed
+    fn with_dir(
+        key_dir: PathBuf,
+        os: OperatingSystem,
+        name: Option<&str>,
+    ) -> Result<Self, KeyManagementError> {
+        // Ensure the directory exists (additional check)
+        fs::create_dir_all(&key_dir)?;
+
+        // Set the full path for the key file
+        let key_path = key_dir.join(Self::key_file_name(name)?);
+
+        info!("Final key path: {:?}", key_path);
+
+        Ok(Self {
+            key_path,
+            os, // Store the detected OS for potential future use
+            password: std::env::var(KEY_PASSWORD_ENV).ok().map(Zeroizing::new),
+        })
+    }
+
+    /// Determines the key storage directory, honouring ROMER_HOME when set
+    fn key_dir(os: &OperatingSystem) -> Result<PathBuf, KeyManagementError> {
+        if let Ok(romer_home) = std::env::var(ROMER_HOME_ENV) {
+            info!("Using {} for key storage: {}", ROMER_HOME_ENV, romer_home);
+            return Ok(PathBuf::from(romer_home));
+        }
 
         // Determine the appropriate key storage directory based on OS
         let key_dir = match os {
@@ -87,18 +234,23 @@ impl NodeKeyManager {
             }
         };
 
-        // Ensure the directory exists (additional check)
-        fs::create_dir_all(&key_dir)?;
-
-        // Set the full path for the key file
-        let key_path = key_dir.join("node.key");
-
-        info!("Final key path: {:?}", key_path);
+        Ok(key_dir)
+    }
 
-        Ok(Self {
-            key_path,
-            os, // Store the detected OS for potential future use
-        })
+    /// Maps an optional key name to its file name
+    fn key_file_name(name: Option<&str>) -> Result<String, KeyManagementError> {
+        match name {
+            None => Ok(DEFAULT_KEY_FILE.to_string()),
+            Some(name)
+                if !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') =>
+            {
+                Ok(format!("node-{}.key", name))
+            }
+            Some(name) => Err(KeyManagementError::InvalidKeyName(name.to_string())),
+        }
     }
 
     /// Initializes the node key, either loading an existing key or generating a new one
@@ -136,9 +288,56 @@ impl NodeKeyManager {
         Ok(signer)
     }
 
+    /// Returns the key passphrase, prompting on the terminal if it isn't set in the environment
+    fn passphrase(&self) -> Result<Zeroizing<String>, KeyManagementError> {
+        if let Some(password) = &self.password {
+            return Ok(password.clone());
+        }
+
+        Self::prompt("Validator key passphrase: ")
+    }
+
+    /// Returns the passphrase for a new key file. A prompted passphrase must be
+    /// typed twice, and an empty one is rejected either way.
+    fn new_passphrase(&self) -> Result<Zeroizing<String>, KeyManagementError> {
+        let password = match &self.password {
+            Some(password) => password.clone(),
+            None => {
+                let password = Self::prompt("New validator key passphrase: ")?;
+                let confirmation = Self::prompt("Repeat passphrase: ")?;
+                if *password != *confirmation {
+                    return Err(KeyManagementError::Passphrase(
+                        "Passphrases do not match".to_string(),
+                    ));
+                }
+                password
+            }
+        };
+
+        if password.is_empty() {
+            return Err(KeyManagementError::Passphrase(
+                "Passphrase must not be empty".to_string(),
+            ));
+        }
+        Ok(password)
+    }
+
+    /// Reads a passphrase from the terminal without echoing it
+    fn prompt(message: &str) -> Result<Zeroizing<String>, KeyManagementError> {
+        rpassword::prompt_password(message)
+            .map(Zeroizing::new)
+            .map_err(|e| {
+                KeyManagementError::Passphrase(format!(
+                    "No passphrase available (set {}): {}",
+                    KEY_PASSWORD_ENV, e
+                ))
+            })
+    }
+
     fn save_key(&self, signer: &Ed25519) -> Result<(), KeyManagementError> {
-        // Retrieve the private key bytes
-        let private_key_bytes = signer.private_key();
+        // Encrypt the private key bytes before they touch the disk
+        let private_key = Zeroizing::new(signer.private_key().to_vec());
+        let private_key_bytes = encrypt_key(&private_key, &self.new_passphrase()?)?;
 
         // Ensure the parent directory exists
         if let Some(parent_dir) = self.key_path.parent() {
@@ -172,16 +371,26 @@ impl NodeKeyManager {
         }
 
         // Read the entire file contents
-        let key_bytes = std::fs::read(&self.key_path).map_err(|e| KeyManagementError::Io(e))?;
+        let key_bytes = Zeroizing::new(std::fs::read(&self.key_path)?);
 
         // Validate key bytes
         if key_bytes.is_empty() {
             return Err(KeyManagementError::Crypto("Empty key file".to_string()));
         }
 
+        // Older key files hold the raw private key
+        let key_bytes = if is_encrypted(&key_bytes) {
+            decrypt_key(&key_bytes, &self.passphrase()?)?
+        } else {
+            warn!(
+                "Key file {:?} is not encrypted; regenerate it to protect it with a passphrase",
+                self.key_path
+            );
+            key_bytes
+        };
+
         // Attempt to reconstruct the private key
-        let private_key = PrivateKey::try_from(key_bytes)
-            .map_err(|e| KeyManagementError::Crypto(format!("Invalid key format: {}", e)))?;
+        let private_key = PrivateKey::copy_from_slice(&key_bytes);
 
         // Reconstruct the signer from the private key
         <Ed25519 as Scheme>::from(private_key)
@@ -189,6 +398,35 @@ impl NodeKeyManager {
             .map(Some)
     }
 
+    /// Lists every key file in the key directory, sorted by path
+    pub fn list_keys(&self) -> Result<Vec<PathBuf>, KeyManagementError> {
+        let key_dir = match self.key_path.parent() {
+            Some(dir) => dir,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut keys = Vec::new();
+        for entry in fs::read_dir(key_dir)? {
+            let path = entry?.path();
+            let is_key = path.file_name().and_then(|n| n.to_str()).is_some_and(|n| {
+                n == DEFAULT_KEY_FILE || (n.starts_with("node-") && n.ends_with(".key"))
+            });
+            if is_key && path.is_file() {
+                keys.push(path);
+            }
+        }
+
+        keys.sort();
+        Ok(keys)
+    }
+
+    /// Deletes this manager's key file
+    pub fn delete_key(&self) -> Result<(), KeyManagementError> {
+        fs::remove_file(&self.key_path)?;
+        info!("Deleted key file: {:?}", self.key_path);
+        Ok(())
+    }
+
     /// Retrieves the current key path
     pub fn key_path(&self) -> &PathBuf {
         &self.key_path
@@ -204,3 +442,90 @@ impl NodeKeyManager {
         &self.os
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_round_trip() {
+        let signer = Ed25519::from_seed(0);
+        let private_key = signer.private_key();
+
+        let contents = encrypt_key(&private_key, "correct horse").unwrap();
+        assert!(is_encrypted(&contents));
+        assert_ne!(&contents[HEADER_LEN..], private_key.as_ref());
+
+        let decrypted = decrypt_key(&contents, "correct horse").unwrap();
+        assert_eq!(decrypted.as_slice(), private_key.as_ref());
+    }
+
+    #[test]
+    fn test_named_keys() {
+        let key_dir = std::env::temp_dir().join("romer_test_named_keys");
+        let _ = fs::remove_dir_all(&key_dir);
+
+        let manager = |name| {
+            let mut manager =
+                NodeKeyManager::with_dir(key_dir.clone(), OperatingSystem::Linux, name).unwrap();
+            manager.password = Some(Zeroizing::new("correct horse".to_string()));
+            manager
+        };
+
+        let alpha = manager(Some("alpha"));
+        let beta = manager(Some("beta"));
+        let alpha_key = alpha.generate_key().unwrap();
+        beta.generate_key().unwrap();
+
+        assert_eq!(
+            alpha.list_keys().unwrap(),
+            vec![
+                key_dir.join("node-alpha.key"),
+                key_dir.join("node-beta.key")
+            ]
+        );
+        assert_eq!(
+            alpha.check_existing_key().unwrap().unwrap().public_key(),
+            alpha_key.public_key()
+        );
+
+        beta.delete_key().unwrap();
+        assert_eq!(
+            manager(None).list_keys().unwrap(),
+            vec![key_dir.join("node-alpha.key")]
+        );
+
+        assert!(matches!(
+            NodeKeyManager::with_dir(key_dir.clone(), OperatingSystem::Linux, Some("../evil")),
+            Err(KeyManagementError::InvalidKeyName(_))
+        ));
+
+        fs::remove_dir_all(&key_dir).unwrap();
+    }
+
+    #[test]
+    fn test_decrypt_wrong_password() {
+        let private_key = Ed25519::from_seed(0).private_key();
+        let contents = encrypt_key(&private_key, "correct horse").unwrap();
+
+        assert!(matches!(
+            decrypt_key(&contents, "battery staple"),
+            Err(KeyManagementError::Crypto(_))
+        ));
+    }
+
+    #[test]
+    fn test_empty_passphrase_rejected() {
+        let key_dir = tempfile::tempdir().unwrap();
+        let mut manager =
+            NodeKeyManager::with_dir(key_dir.path().to_path_buf(), OperatingSystem::Linux, None)
+                .unwrap();
+        manager.password = Some(Zeroizing::new(String::new()));
+
+        assert!(matches!(
+            manager.generate_key(),
+            Err(KeyManagementError::Passphrase(_))
+        ));
+        assert!(!manager.key_path().exists());
+    }
+}
@@ -5,18 +5,24 @@ mod config;
 mod consensus;
 mod identity;
 mod node;
+mod state;
 mod utils;
 
 use clap::Parser;
+use commonware_cryptography::Scheme;
 use commonware_runtime::deterministic::Executor;
 use commonware_runtime::Runner;
 use identity::keymanager::KeyManagementError;
 use node::validator::NodeError;
+use std::path::Path;
 use tracing::{error, info};
 
-use crate::cmd::cli::NodeCliArgs;
+use crate::cmd::cli::{Command, ConfigCommand, NodeCliArgs, RunArgs};
+use crate::config::template::init_config_dir;
 use crate::identity::keymanager::NodeKeyManager;
-use crate::node::validator::Node;
+use crate::node::hardware_validator::{HardwareDetector, HardwareRequirements};
+use crate::node::operating_regions::{haversine_km, Coordinates, RegionConfig};
+use crate::node::validator::{Node, NodeRole};
 
 fn main() {
     // Parse command line arguments
@@ -28,6 +34,131 @@ fn main() {
         .with_target(true)
         .init();
 
+    match args.command {
+        Command::Run(run_args) => run_node(run_args),
+        Command::Keygen { key_name, force } => generate_key(key_name.as_deref(), force),
+        Command::Benchmark { data_dir } => benchmark(&data_dir),
+        Command::Config {
+            action: ConfigCommand::Init { dir, force },
+        } => {
+            if let Err(e) = init_config_dir(&dir, force) {
+                error!("Failed to initialize configuration: {}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Config {
+            action:
+                ConfigCommand::Regions {
+                    config_dir,
+                    near,
+                    country,
+                },
+        } => list_regions(config_dir.as_deref(), near.as_deref(), country.as_deref()),
+    }
+}
+
+/// Prints the authorized regions grouped by country. Only the region nearest to
+/// `near`, or only the regions in `country`, are printed when one is given.
+fn list_regions(config_dir: Option<&Path>, near: Option<&[f64]>, country: Option<&str>) {
+    let config = match RegionConfig::load(config_dir) {
+        Ok(config) => config,
+        Err(e) => {
+            error!("Failed to load regions: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    match near {
+        Some(&[latitude, longitude]) => match config.nearest_region(latitude, longitude) {
+            Some(region) => {
+                let position = Coordinates {
+                    latitude,
+                    longitude,
+                };
+                // nearest_region only considers regions with coordinates
+                let distance = region
+                    .coordinates
+                    .map(|coordinates| haversine_km(&coordinates, &position))
+                    .unwrap_or_default();
+                println!("{}, {:.0} km away", region, distance);
+            }
+            None => {
+                error!("No region has coordinates to compare against");
+                std::process::exit(1);
+            }
+        },
+        _ => {
+            let countries = match country {
+                Some(country) => vec![country.to_string()],
+                None => config.countries(),
+            };
+            for country in countries {
+                let regions = config.regions_by_country(&country);
+                if regions.is_empty() {
+                    error!("No regions found in {}", country);
+                    std::process::exit(1);
+                }
+
+                println!("{}:", regions[0].jurisdiction_country);
+                for region in regions {
+                    println!("  {}", region);
+                }
+            }
+        }
+    }
+}
+
+/// Opens the default key, or the named key when one is given
+fn key_manager(key_name: Option<&str>) -> Result<NodeKeyManager, KeyManagementError> {
+    match key_name {
+        Some(name) => NodeKeyManager::new_named(name),
+        None => NodeKeyManager::new(),
+    }
+}
+
+/// Prints how this host measures up to the validator hardware requirements.
+/// Unmet requirements are reported, not treated as a failure.
+fn benchmark(data_dir: &Path) {
+    match HardwareDetector::benchmark(&HardwareRequirements::default(), data_dir) {
+        Ok(report) => {
+            println!("{}", report);
+            if report.passed() {
+                println!("This host meets the validator hardware requirements");
+            } else {
+                println!("This host does not meet the validator hardware requirements");
+            }
+        }
+        Err(e) => {
+            error!("Hardware detection failed: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Generates a validator key without starting the node
+fn generate_key(key_name: Option<&str>, force: bool) {
+    let result = key_manager(key_name).and_then(|km| {
+        if km.key_path().exists() && !force {
+            return Err(KeyManagementError::AlreadyExists(km.key_path().clone()));
+        }
+        let signer = km.generate_key()?;
+        Ok((signer, km))
+    });
+
+    match result {
+        Ok((signer, km)) => {
+            info!("Key stored at: {:?}", km.key_path());
+            println!("{}", hex::encode(signer.public_key()));
+        }
+        Err(e) => {
+            error!("Failed to generate key: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Starts the validator node
+fn run_node(args: RunArgs) {
     let romer_ascii = r#"
     ██████╗  ██████╗ ███╗   ███╗███████╗██████╗ 
     ██╔══██╗██╔═══██╗████╗ ████║██╔════╝██╔══██╗
@@ -43,7 +174,7 @@ fn main() {
     info!("Using local address: {}", args.address);
 
     // Initialize the key manager and get the signer in one step
-    let signer = match NodeKeyManager::new().and_then(|km| km.initialize()) {
+    let signer = match key_manager(args.key_name.as_deref()).and_then(|km| km.initialize()) {
         Ok(signer) => signer,
         Err(e) => {
             error!("Failed to initialize key manager: {}", e);
@@ -64,9 +195,14 @@ fn main() {
 
     // Create and run the node with configurations
     info!("Starting Node initialization...");
+    let role = if args.observer {
+        NodeRole::Observer
+    } else {
+        NodeRole::Validator
+    };
 
     Runner::start(executor, async move {
-        let node = match Node::new(runtime.clone(), signer) {
+        let node = match Node::new(runtime.clone(), signer, args.config_dir.as_deref(), role) {
             Ok(node) => {
                 info!("Node successfully initialized");
                 node
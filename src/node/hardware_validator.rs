@@ -1,6 +1,7 @@
 use std::env;
 use std::error::Error;
 use std::fmt;
+use std::path::{Component, Path, Prefix};
 use std::process::Command;
 
 use tracing::info;
@@ -23,6 +24,154 @@ pub enum OperatingSystem {
     Unknown,
 }
 
+/// Minimum resources a validator host must provide
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardwareRequirements {
+    pub min_memory_bytes: u64,
+    pub min_cpu_cores: usize,
+    pub min_disk_bytes: u64,
+}
+
+impl Default for HardwareRequirements {
+    fn default() -> Self {
+        Self {
+            min_memory_bytes: 32 * 1024 * 1024 * 1024,
+            min_cpu_cores: 8,
+            min_disk_bytes: 4 * 1024 * 1024 * 1024 * 1024,
+        }
+    }
+}
+
+/// Resources detected on the current host
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardwareResources {
+    pub total_memory_bytes: u64,
+    pub cpu_cores: usize,
+    pub available_disk_bytes: u64,
+}
+
+/// Detected host resources alongside the requirements they were checked against.
+///
+/// Unlike [`HardwareDetector::verify_requirements`], producing a report never fails
+/// because a requirement is unmet, so operators can see how close a host comes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HardwareReport {
+    pub virtualization: VirtualizationType,
+    pub resources: HardwareResources,
+    pub requirements: HardwareRequirements,
+}
+
+impl HardwareReport {
+    pub fn physical_ok(&self) -> bool {
+        self.virtualization == VirtualizationType::Physical
+    }
+
+    pub fn memory_ok(&self) -> bool {
+        self.resources.total_memory_bytes >= self.requirements.min_memory_bytes
+    }
+
+    pub fn cores_ok(&self) -> bool {
+        self.resources.cpu_cores >= self.requirements.min_cpu_cores
+    }
+
+    pub fn disk_ok(&self) -> bool {
+        self.resources.available_disk_bytes >= self.requirements.min_disk_bytes
+    }
+
+    /// Whether the host meets every requirement for running a validator
+    pub fn passed(&self) -> bool {
+        self.physical_ok() && self.memory_ok() && self.cores_ok() && self.disk_ok()
+    }
+}
+
+impl fmt::Display for HardwareReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const GIB: u64 = 1024 * 1024 * 1024;
+        let status = |ok: bool| if ok { "PASS" } else { "FAIL" };
+
+        let host = match &self.virtualization {
+            VirtualizationType::Physical => "physical".to_string(),
+            VirtualizationType::Virtual(tech) => format!("virtual ({})", tech),
+        };
+        writeln!(
+            f,
+            "[{}] Host: {} (required: physical)",
+            status(self.physical_ok()),
+            host
+        )?;
+        writeln!(
+            f,
+            "[{}] Memory: {} GiB (required: {} GiB)",
+            status(self.memory_ok()),
+            self.resources.total_memory_bytes / GIB,
+            self.requirements.min_memory_bytes / GIB
+        )?;
+        writeln!(
+            f,
+            "[{}] CPU cores: {} (required: {})",
+            status(self.cores_ok()),
+            self.resources.cpu_cores,
+            self.requirements.min_cpu_cores
+        )?;
+        write!(
+            f,
+            "[{}] Free disk: {} GiB (required: {} GiB)",
+            status(self.disk_ok()),
+            self.resources.available_disk_bytes / GIB,
+            self.requirements.min_disk_bytes / GIB
+        )
+    }
+}
+
+/// Error type for hardware requirement verification
+#[derive(Debug)]
+pub enum HardwareError {
+    Detection(HardwareDetectionError),
+    InsufficientMemory { required: u64, available: u64 },
+    InsufficientCores { required: usize, available: usize },
+    InsufficientStorage { required: u64, available: u64 },
+}
+
+impl fmt::Display for HardwareError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HardwareError::Detection(e) => write!(f, "{}", e),
+            HardwareError::InsufficientMemory {
+                required,
+                available,
+            } => write!(
+                f,
+                "Insufficient memory: {} bytes required, {} bytes available",
+                required, available
+            ),
+            HardwareError::InsufficientCores {
+                required,
+                available,
+            } => write!(
+                f,
+                "Insufficient CPU cores: {} required, {} available",
+                required, available
+            ),
+            HardwareError::InsufficientStorage {
+                required,
+                available,
+            } => write!(
+                f,
+                "Insufficient storage: {} bytes required, {} bytes available",
+                required, available
+            ),
+        }
+    }
+}
+
+impl Error for HardwareError {}
+
+impl From<HardwareDetectionError> for HardwareError {
+    fn from(error: HardwareDetectionError) -> Self {
+        HardwareError::Detection(error)
+    }
+}
+
 /// Custom error type for hardware detection
 #[derive(Debug)]
 pub struct HardwareDetectionError {
@@ -197,6 +346,184 @@ impl HardwareDetector {
 
         Ok(VirtualizationType::Physical)
     }
+
+    /// Detect total physical memory in bytes
+    pub fn detect_total_memory() -> Result<u64, HardwareDetectionError> {
+        match Self::detect_os() {
+            OperatingSystem::Linux => {
+                let meminfo = std::fs::read_to_string("/proc/meminfo").map_err(|e| {
+                    HardwareDetectionError::new(format!("Failed to read /proc/meminfo: {}", e))
+                })?;
+
+                // The MemTotal line is reported in kibibytes
+                meminfo
+                    .lines()
+                    .find_map(|line| line.strip_prefix("MemTotal:"))
+                    .and_then(|value| value.trim_end_matches("kB").trim().parse::<u64>().ok())
+                    .map(|kib| kib * 1024)
+                    .ok_or_else(|| {
+                        HardwareDetectionError::new(
+                            "MemTotal not found in /proc/meminfo".to_string(),
+                        )
+                    })
+            }
+            OperatingSystem::MacOS => Self::command_output_u64("sysctl", &["-n", "hw.memsize"]),
+            OperatingSystem::Windows => Self::command_output_u64(
+                "wmic",
+                &["ComputerSystem", "get", "TotalPhysicalMemory", "/value"],
+            ),
+            OperatingSystem::Unknown => Err(HardwareDetectionError::new(
+                "Memory detection is not supported on this operating system".to_string(),
+            )),
+        }
+    }
+
+    /// Detect the number of CPU cores available to the process
+    pub fn detect_cpu_cores() -> Result<usize, HardwareDetectionError> {
+        std::thread::available_parallelism()
+            .map(|cores| cores.get())
+            .map_err(|e| HardwareDetectionError::new(format!("CPU core detection failed: {}", e)))
+    }
+
+    /// Detect free disk space in bytes on the filesystem holding `path`
+    pub fn detect_available_disk(path: &Path) -> Result<u64, HardwareDetectionError> {
+        match Self::detect_os() {
+            OperatingSystem::Linux | OperatingSystem::MacOS => {
+                // POSIX output format: the fourth column of the second line is
+                // the available space in 1024-byte blocks
+                let output = Command::new("df")
+                    .arg("-Pk")
+                    .arg(path)
+                    .output()
+                    .map_err(|e| HardwareDetectionError::new(format!("df query failed: {}", e)))?;
+
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .nth(1)
+                    .and_then(|line| line.split_whitespace().nth(3))
+                    .and_then(|value| value.parse::<u64>().ok())
+                    .map(|kib| kib * 1024)
+                    .ok_or_else(|| {
+                        HardwareDetectionError::new(format!(
+                            "Could not determine free space for {:?}",
+                            path
+                        ))
+                    })
+            }
+            OperatingSystem::Windows => {
+                let drive = Self::windows_drive(path)?;
+                let filter = format!("DeviceID='{}'", drive);
+                Self::command_output_u64(
+                    "wmic",
+                    &[
+                        "logicaldisk",
+                        "where",
+                        &filter,
+                        "get",
+                        "FreeSpace",
+                        "/value",
+                    ],
+                )
+            }
+            OperatingSystem::Unknown => Err(HardwareDetectionError::new(
+                "Disk detection is not supported on this operating system".to_string(),
+            )),
+        }
+    }
+
+    /// Drive letter (e.g. "C:") of the volume holding `path` on Windows
+    fn windows_drive(path: &Path) -> Result<String, HardwareDetectionError> {
+        let path = path.canonicalize().map_err(|e| {
+            HardwareDetectionError::new(format!("Failed to resolve {:?}: {}", path, e))
+        })?;
+
+        match path.components().next() {
+            Some(Component::Prefix(prefix)) => match prefix.kind() {
+                Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                    Ok(format!("{}:", letter as char))
+                }
+                _ => Err(HardwareDetectionError::new(format!(
+                    "{:?} is not on a local drive",
+                    path
+                ))),
+            },
+            _ => Err(HardwareDetectionError::new(format!(
+                "Could not determine the drive for {:?}",
+                path
+            ))),
+        }
+    }
+
+    /// Detect memory, CPU cores and free space in `data_dir`
+    pub fn detect_resources(data_dir: &Path) -> Result<HardwareResources, HardwareDetectionError> {
+        Ok(HardwareResources {
+            total_memory_bytes: Self::detect_total_memory()?,
+            cpu_cores: Self::detect_cpu_cores()?,
+            available_disk_bytes: Self::detect_available_disk(data_dir)?,
+        })
+    }
+
+    /// Detect the host and report how it measures up to `requirements`.
+    ///
+    /// Only detection failures are errors; unmet requirements are recorded in the report.
+    pub fn benchmark(
+        requirements: &HardwareRequirements,
+        data_dir: &Path,
+    ) -> Result<HardwareReport, HardwareDetectionError> {
+        Ok(HardwareReport {
+            virtualization: Self::detect_virtualization()?,
+            resources: Self::detect_resources(data_dir)?,
+            requirements: requirements.clone(),
+        })
+    }
+
+    /// Detect host resources and check them against the given requirements.
+    /// `data_dir` is the directory the node will store chain data in.
+    pub fn verify_requirements(
+        requirements: &HardwareRequirements,
+        data_dir: &Path,
+    ) -> Result<HardwareResources, HardwareError> {
+        let resources = Self::detect_resources(data_dir)?;
+
+        if resources.total_memory_bytes < requirements.min_memory_bytes {
+            return Err(HardwareError::InsufficientMemory {
+                required: requirements.min_memory_bytes,
+                available: resources.total_memory_bytes,
+            });
+        }
+
+        if resources.cpu_cores < requirements.min_cpu_cores {
+            return Err(HardwareError::InsufficientCores {
+                required: requirements.min_cpu_cores,
+                available: resources.cpu_cores,
+            });
+        }
+
+        if resources.available_disk_bytes < requirements.min_disk_bytes {
+            return Err(HardwareError::InsufficientStorage {
+                required: requirements.min_disk_bytes,
+                available: resources.available_disk_bytes,
+            });
+        }
+
+        Ok(resources)
+    }
+
+    /// Run a command and parse the first number in its output
+    fn command_output_u64(program: &str, args: &[&str]) -> Result<u64, HardwareDetectionError> {
+        let output = Command::new(program)
+            .args(args)
+            .output()
+            .map_err(|e| HardwareDetectionError::new(format!("{} query failed: {}", program, e)))?;
+
+        String::from_utf8_lossy(&output.stdout)
+            .split(|c: char| !c.is_ascii_digit())
+            .find(|token| !token.is_empty())
+            .and_then(|token| token.parse::<u64>().ok())
+            .ok_or_else(|| {
+                HardwareDetectionError::new(format!("Unexpected output from {}", program))
+            })
+    }
 }
 
 /// Unit tests for hardware detection
@@ -226,6 +553,58 @@ mod tests {
         let result = HardwareDetector::detect_virtualization();
         assert!(result.is_ok(), "Virtualization detection should not fail");
     }
+
+    /// Test that resource detection reports plausible values on the test host
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    #[test]
+    fn test_resource_detection() {
+        assert!(HardwareDetector::detect_total_memory().unwrap() > 0);
+        assert!(HardwareDetector::detect_cpu_cores().unwrap() > 0);
+        assert!(HardwareDetector::detect_available_disk(&env::temp_dir()).unwrap() > 0);
+    }
+
+    /// Test that unmet requirements are reported
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    #[test]
+    fn test_verify_requirements() {
+        let data_dir = env::temp_dir();
+
+        let minimal = HardwareRequirements {
+            min_memory_bytes: 1,
+            min_cpu_cores: 1,
+            min_disk_bytes: 1,
+        };
+        assert!(HardwareDetector::verify_requirements(&minimal, &data_dir).is_ok());
+
+        let too_many_cores = HardwareRequirements {
+            min_cpu_cores: usize::MAX,
+            ..minimal
+        };
+        assert!(matches!(
+            HardwareDetector::verify_requirements(&too_many_cores, &data_dir),
+            Err(HardwareError::InsufficientCores { .. })
+        ));
+    }
+
+    /// Test that a benchmark reports unmet requirements instead of failing
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    #[test]
+    fn test_benchmark_reports_each_criterion() {
+        let requirements = HardwareRequirements {
+            min_memory_bytes: 1,
+            min_cpu_cores: usize::MAX,
+            min_disk_bytes: 1,
+        };
+        let report = HardwareDetector::benchmark(&requirements, &env::temp_dir()).unwrap();
+
+        assert_eq!(report.requirements, requirements);
+        assert!(report.resources.total_memory_bytes > 0);
+        assert!(report.memory_ok());
+        assert!(report.disk_ok());
+        assert!(!report.cores_ok());
+        assert!(!report.passed());
+        assert!(report.to_string().contains("FAIL"));
+    }
 }
 
 /// Example main function to demonstrate usage
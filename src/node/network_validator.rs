@@ -1,8 +1,13 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::net::{IpAddr, SocketAddr};
 use std::time::{Duration, Instant};
 use async_std::net::TcpStream;
 use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::config::validator::ValidatorConfig;
+use crate::node::operating_regions::RegionConfig;
 
 /// Represents a major Internet Exchange Point (IX)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,7 +23,8 @@ pub struct InternetExchangePoint {
 pub struct LocationVerificationConfig {
     pub max_rtt_threshold_ms: u64,
     pub min_ix_responses: usize,
-    pub speed_of_light_factor: f64,
+    /// Number of probes sent to each endpoint when measuring latency
+    pub samples_per_endpoint: usize,
 }
 
 /// Represents a validator's location verification result
@@ -27,9 +33,15 @@ pub struct LocationVerificationResult {
     pub estimated_region: Option<String>,
     pub network_performance: NetworkPerformance,
     pub is_verified: bool,
+    /// Set when the claimed region disagrees with the lowest-latency region
+    pub region_mismatch: bool,
 }
 
-/// Tracks network performance metrics
+/// Tracks network performance metrics.
+///
+/// Each responding IX endpoint contributes one RTT, the median of its samples, so
+/// `total_latency` is the sum over endpoints and `response_count` the number of
+/// endpoints that answered.
 #[derive(Debug, Default)]
 pub struct NetworkPerformance {
     pub ix_rtt_measurements: HashMap<String, Duration>,
@@ -37,15 +49,59 @@ pub struct NetworkPerformance {
     pub response_count: usize,
 }
 
+/// Measures round-trip time to a network endpoint
+pub trait LatencyProber {
+    /// Returns the round-trip time to `address`, or None if it didn't respond
+    fn probe(&self, address: IpAddr) -> impl Future<Output = Option<Duration>> + Send;
+}
+
+/// How long a single TCP probe may take before the endpoint counts as unresponsive
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Probes endpoints by timing a TCP connection to port 80
+pub struct TcpProber;
+
+impl LatencyProber for TcpProber {
+    async fn probe(&self, address: IpAddr) -> Option<Duration> {
+        let socket_addr = SocketAddr::new(address, 80);
+
+        let start = Instant::now();
+        match async_std::future::timeout(PROBE_TIMEOUT, TcpStream::connect(socket_addr)).await {
+            Ok(Ok(_)) => Some(start.elapsed()),
+            _ => None,
+        }
+    }
+}
+
+/// Median of the samples after discarding outliers above twice the raw median
+fn filtered_median(mut samples: Vec<Duration>) -> Option<Duration> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    samples.sort();
+    let cutoff = samples[samples.len() / 2] * 2;
+    samples.retain(|sample| *sample <= cutoff);
+    Some(samples[samples.len() / 2])
+}
+
 /// Main location verification service
-pub struct LocationVerificationService {
+pub struct LocationVerificationService<P: LatencyProber = TcpProber> {
     known_ixps: Vec<InternetExchangePoint>,
     config: LocationVerificationConfig,
+    prober: P,
 }
 
-impl LocationVerificationService {
+impl LocationVerificationService<TcpProber> {
     /// Creates a new location verification service with predefined IXPs
     pub fn new() -> Self {
+        Self::with_prober(TcpProber)
+    }
+}
+
+impl<P: LatencyProber> LocationVerificationService<P> {
+    /// Creates a location verification service that measures latency with `prober`
+    pub fn with_prober(prober: P) -> Self {
         let default_ixps = vec![
             InternetExchangePoint {
                 name: "AMS-IX".to_string(),
@@ -67,37 +123,91 @@ impl LocationVerificationService {
             config: LocationVerificationConfig {
                 max_rtt_threshold_ms: 250,
                 min_ix_responses: 2,
-                speed_of_light_factor: 0.7, // Accounting for network routing
+                samples_per_endpoint: 5,
             },
+            prober,
         }
     }
 
     /// Measure round-trip time to an IX point
     pub async fn measure_rtt(&self, ix_point: &IpAddr) -> Option<Duration> {
-        let socket_addr = SocketAddr::new(*ix_point, 80);
-        
-        let start = Instant::now();
-        match async_std::net::TcpStream::connect(socket_addr).await {
-            Ok(_) => Some(start.elapsed()),
-            Err(_) => None
-        }
+        self.prober.probe(*ix_point).await
     }
 
-    /// Verify validator location based on network measurements
-    pub async fn verify_location(&self, validator_ip: IpAddr) -> LocationVerificationResult {
-        let mut performance = NetworkPerformance::default();
+    /// Samples every endpoint of every known IX point `samples_per_endpoint` times
+    async fn sample_endpoints(&self) -> Vec<(&InternetExchangePoint, Vec<Duration>)> {
+        let mut endpoints = Vec::new();
 
-        // Measure RTT to multiple IX points
         for ix_point in &self.known_ixps {
             for ix_ip in &ix_point.ip_addresses {
-                if let Some(rtt) = self.measure_rtt(ix_ip).await {
-                    performance.ix_rtt_measurements.insert(
-                        ix_point.name.clone(), 
-                        rtt
-                    );
-                    performance.total_latency += rtt;
-                    performance.response_count += 1;
+                let mut samples = Vec::new();
+                for _ in 0..self.config.samples_per_endpoint {
+                    if let Some(rtt) = self.measure_rtt(ix_ip).await {
+                        samples.push(rtt);
+                    }
                 }
+                endpoints.push((ix_point, samples));
+            }
+        }
+
+        endpoints
+    }
+
+    /// Median RTT per region, pooling the samples of all of a region's endpoints.
+    ///
+    /// Samples more than twice the region's median are treated as outliers and
+    /// dropped. Regions where no endpoint answered are left out.
+    fn region_latencies(
+        endpoints: &[(&InternetExchangePoint, Vec<Duration>)],
+    ) -> HashMap<String, Duration> {
+        let mut samples: HashMap<String, Vec<Duration>> = HashMap::new();
+        for (ix_point, rtts) in endpoints {
+            samples
+                .entry(ix_point.region.clone())
+                .or_default()
+                .extend(rtts);
+        }
+
+        samples
+            .into_iter()
+            .filter_map(|(region, samples)| filtered_median(samples).map(|median| (region, median)))
+            .collect()
+    }
+
+    /// Probes every known IX point and returns the median RTT for each region
+    pub async fn measure_region_latencies(&self) -> HashMap<String, Duration> {
+        Self::region_latencies(&self.sample_endpoints().await)
+    }
+
+    /// Region of the known IX point with the given name, used to turn a
+    /// validator's configured city into a region it can be checked against
+    pub fn exchange_region(&self, exchange: &str) -> Option<&str> {
+        self.known_ixps
+            .iter()
+            .find(|ix_point| ix_point.name.eq_ignore_ascii_case(exchange))
+            .map(|ix_point| ix_point.region.as_str())
+    }
+
+    /// Verify validator location based on network measurements.
+    ///
+    /// When the validator claims a region, it is cross-checked against the
+    /// region with the lowest median latency and a disagreement fails verification.
+    pub async fn verify_location(
+        &self,
+        validator_ip: IpAddr,
+        claimed_region: Option<&str>,
+    ) -> LocationVerificationResult {
+        let mut performance = NetworkPerformance::default();
+        let endpoints = self.sample_endpoints().await;
+
+        // One RTT per responding IX endpoint, as the threshold expects
+        for (ix_point, samples) in &endpoints {
+            if let Some(rtt) = filtered_median(samples.clone()) {
+                performance
+                    .ix_rtt_measurements
+                    .insert(ix_point.name.clone(), rtt);
+                performance.total_latency += rtt;
+                performance.response_count += 1;
             }
         }
 
@@ -106,25 +216,25 @@ impl LocationVerificationService {
             && performance.total_latency.as_millis() as u64 <= self.config.max_rtt_threshold_ms;
 
         let estimated_region = if is_verified {
-            // Simple region estimation based on lowest latency
-            performance.ix_rtt_measurements
-                .iter()
-                .min_by_key(|&(_, duration)| *duration)
-                .map(|(name, _)| {
-                    self.known_ixps
-                        .iter()
-                        .find(|ix| ix.name == *name)
-                        .map(|ix| ix.region.clone())
-                        .unwrap_or_default()
-                })
+            // Region with the lowest median latency across its endpoints
+            Self::region_latencies(&endpoints)
+                .into_iter()
+                .min_by_key(|(_, duration)| *duration)
+                .map(|(region, _)| region)
         } else {
             None
         };
 
+        let region_mismatch = match (claimed_region, &estimated_region) {
+            (Some(claimed), Some(estimated)) => !claimed.eq_ignore_ascii_case(estimated),
+            _ => false,
+        };
+
         LocationVerificationResult {
             estimated_region,
             network_performance: performance,
-            is_verified,
+            is_verified: is_verified && !region_mismatch,
+            region_mismatch,
         }
     }
 
@@ -138,18 +248,184 @@ impl LocationVerificationService {
     }
 }
 
-/// Example usage in validator registration flow
-async fn validate_validator_location(validator_ip: IpAddr) {
+/// Checks at validator startup that measured latencies agree with the city in
+/// the validator configuration
+pub async fn validate_validator_location(
+    validator_ip: IpAddr,
+    validator_config: &ValidatorConfig,
+    regions: &RegionConfig,
+) -> LocationVerificationResult {
     let location_service = LocationVerificationService::new();
-    let verification_result = location_service.verify_location(validator_ip).await;
+
+    // The configured city is checked through the region of its internet exchange.
+    // Cities whose exchange isn't probed can't be cross-checked.
+    let city_key = validator_config.city.to_lowercase().replace(' ', "-");
+    let claimed_region = regions
+        .regions
+        .city
+        .get(&city_key)
+        .and_then(|city| location_service.exchange_region(&city.internet_exchange));
+
+    let verification_result = location_service
+        .verify_location(validator_ip, claimed_region)
+        .await;
 
     match verification_result.is_verified {
         true => {
-            println!("Validator Location Verified");
-            println!("Estimated Region: {:?}", verification_result.estimated_region);
+            info!("Validator location verified");
+            info!("Estimated region: {:?}", verification_result.estimated_region);
         },
         false => {
-            println!("Location Verification Failed");
+            warn!(
+                "Location verification failed: {} IX endpoints responded, region mismatch: {}",
+                verification_result.network_performance.response_count,
+                verification_result.region_mismatch
+            );
         }
     }
-}
\ No newline at end of file
+
+    verification_result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commonware_runtime::{deterministic::Executor, Runner};
+    use std::sync::Mutex;
+
+    /// Replays a fixed sequence of RTTs per address
+    struct MockProber {
+        responses: HashMap<IpAddr, Vec<Duration>>,
+        calls: Mutex<HashMap<IpAddr, usize>>,
+    }
+
+    impl MockProber {
+        fn new(responses: &[(&str, &[u64])]) -> Self {
+            Self {
+                responses: responses
+                    .iter()
+                    .map(|(ip, rtts)| {
+                        (
+                            ip.parse().unwrap(),
+                            rtts.iter().map(|ms| Duration::from_millis(*ms)).collect(),
+                        )
+                    })
+                    .collect(),
+                calls: Mutex::new(HashMap::new()),
+            }
+        }
+    }
+
+    impl LatencyProber for MockProber {
+        async fn probe(&self, address: IpAddr) -> Option<Duration> {
+            let rtts = self.responses.get(&address)?;
+            let mut calls = self.calls.lock().unwrap();
+            let call = calls.entry(address).or_default();
+            let rtt = rtts[*call % rtts.len()];
+            *call += 1;
+            Some(rtt)
+        }
+    }
+
+    fn ixp(name: &str, ip: &str, region: &str) -> InternetExchangePoint {
+        InternetExchangePoint {
+            name: name.to_string(),
+            coordinates: (0.0, 0.0),
+            ip_addresses: vec![ip.parse().unwrap()],
+            region: region.to_string(),
+        }
+    }
+
+    fn service(prober: MockProber) -> LocationVerificationService<MockProber> {
+        let mut service = LocationVerificationService::with_prober(prober);
+        service.known_ixps = vec![
+            ixp("AMS-IX", "10.0.0.1", "Europe"),
+            ixp("JPNAP", "10.0.0.2", "Asia"),
+            ixp("Offline", "10.0.0.3", "Americas"),
+        ];
+        service
+    }
+
+    #[test]
+    fn test_filtered_median_discards_outliers() {
+        let samples = [10, 12, 11, 500, 13].map(Duration::from_millis).to_vec();
+        assert_eq!(filtered_median(samples), Some(Duration::from_millis(12)));
+        assert_eq!(filtered_median(vec![]), None);
+    }
+
+    #[test]
+    fn test_measure_region_latencies() {
+        let (executor, _, _) = Executor::default();
+        executor.start(async move {
+            let service = service(MockProber::new(&[
+                ("10.0.0.1", &[10, 12, 900, 11, 13]),
+                ("10.0.0.2", &[120, 130, 125, 122, 128]),
+            ]));
+
+            let latencies = service.measure_region_latencies().await;
+            assert_eq!(latencies.len(), 2);
+            assert_eq!(latencies["Europe"], Duration::from_millis(12));
+            assert_eq!(latencies["Asia"], Duration::from_millis(125));
+        });
+    }
+
+    #[test]
+    fn test_verify_location_sums_endpoint_latency() {
+        let (executor, _, _) = Executor::default();
+        executor.start(async move {
+            let mut service = service(MockProber::new(&[
+                ("10.0.0.1", &[10, 12, 900, 11, 13]),
+                ("10.0.0.2", &[120, 130, 125, 122, 128]),
+                ("10.0.0.4", &[20, 21, 22, 23, 24]),
+            ]));
+            service.known_ixps.push(ixp("LINX", "10.0.0.4", "Europe"));
+
+            let result = service
+                .verify_location("192.0.2.1".parse().unwrap(), None)
+                .await;
+
+            // One median RTT per responding endpoint, keyed by IX name
+            let performance = &result.network_performance;
+            assert_eq!(performance.response_count, 3);
+            assert_eq!(
+                performance.ix_rtt_measurements["LINX"],
+                Duration::from_millis(22)
+            );
+            assert_eq!(
+                performance.total_latency,
+                Duration::from_millis(12 + 125 + 22)
+            );
+            assert_eq!(result.estimated_region.as_deref(), Some("Europe"));
+        });
+    }
+
+    #[test]
+    fn test_exchange_region() {
+        let service = service(MockProber::new(&[]));
+        assert_eq!(service.exchange_region("ams-ix"), Some("Europe"));
+        assert_eq!(service.exchange_region("SGIX"), None);
+    }
+
+    #[test]
+    fn test_verify_location_flags_region_mismatch() {
+        let (executor, _, _) = Executor::default();
+        executor.start(async move {
+            let responses: &[(&str, &[u64])] =
+                &[("10.0.0.1", &[10, 12, 11]), ("10.0.0.2", &[60, 70, 65])];
+            let validator_ip = "192.0.2.1".parse().unwrap();
+
+            let result = service(MockProber::new(responses))
+                .verify_location(validator_ip, Some("Europe"))
+                .await;
+            assert_eq!(result.estimated_region.as_deref(), Some("Europe"));
+            assert!(!result.region_mismatch);
+            assert!(result.is_verified);
+
+            let result = service(MockProber::new(responses))
+                .verify_location(validator_ip, Some("Asia"))
+                .await;
+            assert!(result.region_mismatch);
+            assert!(!result.is_verified);
+        });
+    }
+}
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::Path;
 use std::fs;
+use tracing::warn;
+
+use crate::config::template::{DEFAULT_CONFIG_DIR, REGIONS_FILE};
 
 #[derive(Debug)]
 pub enum RegionError {
@@ -19,6 +22,28 @@ pub struct CityRegion {
     pub flag: String,
     pub region_code: String,
     pub internet_exchange: String,
+    pub coordinates: Option<Coordinates>,
+}
+
+// Geographic position of a region in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+// Mean Earth radius used for great-circle distances
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+// Great-circle distance between two points in kilometres, using the haversine formula
+pub fn haversine_km(a: &Coordinates, b: &Coordinates) -> f64 {
+    let (lat_a, lat_b) = (a.latitude.to_radians(), b.latitude.to_radians());
+    let delta_lat = lat_b - lat_a;
+    let delta_lon = (b.longitude - a.longitude).to_radians();
+
+    let h = (delta_lat / 2.0).sin().powi(2)
+        + lat_a.cos() * lat_b.cos() * (delta_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
 }
 
 // Container for different types of regions
@@ -28,6 +53,18 @@ pub struct RegionTypes {
     pub city: HashMap<String, CityRegion>,
 }
 
+// How a merge treats a city that is already present in the target configuration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeConflict {
+    // Reject the whole merge
+    Error,
+    // Keep the existing region, log a warning, and continue
+    Skip,
+}
+
+// Commented default region list written by `config init`
+const TEMPLATE: &str = include_str!("../../config/regions.toml");
+
 // Top-level configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegionConfig {
@@ -35,9 +72,11 @@ pub struct RegionConfig {
 }
 
 impl RegionConfig {
-    pub fn load() -> Result<Self, RegionError> {
+    pub fn load(config_dir: Option<&Path>) -> Result<Self, RegionError> {
         // Look for the configuration file in the config directory
-        let config_path = PathBuf::from("config/regions.toml");
+        let config_path = config_dir
+            .unwrap_or(Path::new(DEFAULT_CONFIG_DIR))
+            .join(REGIONS_FILE);
         
         let contents = fs::read_to_string(config_path)
             .map_err(|e| RegionError::IoError(e))?;
@@ -51,6 +90,13 @@ impl RegionConfig {
         Ok(config)
     }
 
+    // Writes the default region list to `path`, refusing to replace an existing
+    // file unless `force` is set
+    pub fn write_template<P: AsRef<Path>>(path: P, force: bool) -> Result<(), RegionError> {
+        crate::config::template::write_file(path.as_ref(), TEMPLATE, force)?;
+        Ok(())
+    }
+
     fn validate(&self) -> Result<(), RegionError> {
         // Ensure we have at least one city region defined
         if self.regions.city.is_empty() {
@@ -77,17 +123,117 @@ impl RegionConfig {
         Ok(())
     }
 
+    // Adds regions from `other` whose id and city are not already present.
+    //
+    // With MergeConflict::Error nothing is merged if any id or city conflicts, so
+    // the configuration is never left half-merged.
+    pub fn merge(
+        &mut self,
+        other: RegionConfig,
+        on_conflict: MergeConflict,
+    ) -> Result<(), RegionError> {
+        let mut conflicts: Vec<String> = other
+            .regions
+            .city
+            .iter()
+            .filter(|(id, region)| self.conflicts_with(id, region))
+            .map(|(id, region)| format!("{} ({})", region.city, id))
+            .collect();
+        conflicts.sort();
+
+        if on_conflict == MergeConflict::Error && !conflicts.is_empty() {
+            return Err(RegionError::ValidationError(format!(
+                "Cannot merge regions, already defined: {}",
+                conflicts.join(", ")
+            )));
+        }
+
+        for (id, region) in other.regions.city {
+            if self.conflicts_with(&id, &region) {
+                warn!(
+                    "Skipping region {} during merge, {} is already defined",
+                    id, region.city
+                );
+                continue;
+            }
+            self.regions.city.insert(id, region);
+        }
+
+        Ok(())
+    }
+
+    // Whether a region's id is taken or its city already defined
+    fn conflicts_with(&self, id: &str, region: &CityRegion) -> bool {
+        self.regions.city.contains_key(id) || self.has_city(&region.city)
+    }
+
+    // Case-insensitive check for an existing city name
+    fn has_city(&self, city: &str) -> bool {
+        self.regions
+            .city
+            .values()
+            .any(|region| region.city.eq_ignore_ascii_case(city))
+    }
+
+    // Finds the region closest to the given position. Regions without
+    // coordinates are ignored.
+    pub fn nearest_region(&self, latitude: f64, longitude: f64) -> Option<&CityRegion> {
+        let target = Coordinates {
+            latitude,
+            longitude,
+        };
+
+        self.regions
+            .city
+            .values()
+            .filter_map(|region| {
+                region
+                    .coordinates
+                    .map(|coordinates| (region, haversine_km(&coordinates, &target)))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(region, _)| region)
+    }
+
+    // All regions in a country, whatever their state or subdivision. Matching is
+    // case-insensitive and the result is ordered by city name.
+    pub fn regions_by_country(&self, country: &str) -> Vec<&CityRegion> {
+        let mut regions: Vec<&CityRegion> = self
+            .regions
+            .city
+            .values()
+            .filter(|region| region.jurisdiction_country.eq_ignore_ascii_case(country))
+            .collect();
+        regions.sort_by(|a, b| a.city.cmp(&b.city));
+        regions
+    }
+
+    // Distinct countries with at least one region, sorted alphabetically
+    pub fn countries(&self) -> Vec<String> {
+        let mut countries: Vec<String> = self
+            .regions
+            .city
+            .values()
+            .map(|region| region.jurisdiction_country.clone())
+            .collect();
+        countries.sort();
+        countries.dedup();
+        countries
+    }
+
     // Helper method to format region information for display
     pub fn get_city_display(&self, region_id: &str) -> Option<String> {
-        self.regions.city.get(region_id).map(|region| {
-            format!(
-                "{} {} ({}, {})",
-                region.flag,
-                region.city,
-                region.jurisdiction_state,
-                region.jurisdiction_country
-            )
-        })
+        self.regions.city.get(region_id).map(|region| region.to_string())
+    }
+}
+
+impl std::fmt::Display for CityRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} ({}, {})",
+            self.flag, self.city, self.jurisdiction_state, self.jurisdiction_country
+        )
     }
 }
 
@@ -115,3 +261,177 @@ impl From<toml::de::Error> for RegionError {
         RegionError::ParseError(error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn city_region(city: &str, country: &str) -> CityRegion {
+        CityRegion {
+            city: city.to_string(),
+            jurisdiction_country: country.to_string(),
+            jurisdiction_state: city.to_string(),
+            flag: "🏳".to_string(),
+            region_code: country[..2].to_uppercase(),
+            internet_exchange: format!("{} IX", city),
+            coordinates: None,
+        }
+    }
+
+    fn region_config(cities: &[(&str, &str)]) -> RegionConfig {
+        RegionConfig {
+            regions: RegionTypes {
+                city: cities
+                    .iter()
+                    .map(|(city, country)| (city.to_lowercase(), city_region(city, country)))
+                    .collect(),
+            },
+        }
+    }
+
+    fn located(city: &str, latitude: f64, longitude: f64) -> (String, CityRegion) {
+        let mut region = city_region(city, "Somewhere");
+        region.coordinates = Some(Coordinates {
+            latitude,
+            longitude,
+        });
+        (city.to_lowercase(), region)
+    }
+
+    #[test]
+    fn test_haversine_km() {
+        let frankfurt = Coordinates {
+            latitude: 50.11,
+            longitude: 8.68,
+        };
+        let amsterdam = Coordinates {
+            latitude: 52.37,
+            longitude: 4.90,
+        };
+        let tokyo = Coordinates {
+            latitude: 35.68,
+            longitude: 139.69,
+        };
+
+        assert_eq!(haversine_km(&frankfurt, &frankfurt), 0.0);
+        let to_amsterdam = haversine_km(&frankfurt, &amsterdam);
+        assert!((350.0..380.0).contains(&to_amsterdam));
+        assert!(to_amsterdam < haversine_km(&frankfurt, &tokyo));
+    }
+
+    #[test]
+    fn test_nearest_region() {
+        let config = RegionConfig {
+            regions: RegionTypes {
+                city: [
+                    located("Amsterdam", 52.37, 4.90),
+                    located("Tokyo", 35.68, 139.69),
+                ]
+                .into_iter()
+                .collect(),
+            },
+        };
+
+        // Frankfurt is closer to Amsterdam than to Tokyo
+        let nearest = config.nearest_region(50.11, 8.68).unwrap();
+        assert_eq!(nearest.city, "Amsterdam");
+
+        // Regions without coordinates are skipped
+        let unlocated = region_config(&[("Tokyo", "Japan")]);
+        assert!(unlocated.nearest_region(50.11, 8.68).is_none());
+    }
+
+    #[test]
+    fn test_merge_disjoint_regions() {
+        let mut config = region_config(&[("Tokyo", "Japan"), ("Singapore", "Singapore")]);
+        let other = region_config(&[("Amsterdam", "Netherlands")]);
+
+        config.merge(other, MergeConflict::Error).unwrap();
+
+        assert_eq!(config.regions.city.len(), 3);
+        assert!(config.regions.city.contains_key("amsterdam"));
+    }
+
+    #[test]
+    fn test_merge_overlapping_regions() {
+        let base = region_config(&[("Tokyo", "Japan"), ("Singapore", "Singapore")]);
+        let other = region_config(&[("Tokyo", "Japan"), ("Amsterdam", "Netherlands")]);
+
+        // Conflicts reject the whole merge
+        let mut config = base.clone();
+        assert!(matches!(
+            config.merge(other.clone(), MergeConflict::Error),
+            Err(RegionError::ValidationError(_))
+        ));
+        assert_eq!(config.regions.city.len(), 2);
+
+        // Conflicts are skipped and the rest merged
+        let mut config = base.clone();
+        config.merge(other, MergeConflict::Skip).unwrap();
+        assert_eq!(config.regions.city.len(), 3);
+        assert!(config.regions.city.contains_key("amsterdam"));
+    }
+
+    #[test]
+    fn test_merge_conflicting_region_id() {
+        let base = region_config(&[("Tokyo", "Japan")]);
+
+        // A different city filed under an id that is already taken
+        let mut other = region_config(&[("Amsterdam", "Netherlands")]);
+        let osaka = city_region("Osaka", "Japan");
+        other.regions.city.insert("tokyo".to_string(), osaka);
+
+        let mut config = base.clone();
+        assert!(matches!(
+            config.merge(other.clone(), MergeConflict::Error),
+            Err(RegionError::ValidationError(_))
+        ));
+        assert_eq!(config.regions.city.len(), 1);
+
+        let mut config = base;
+        config.merge(other, MergeConflict::Skip).unwrap();
+        assert_eq!(config.regions.city.len(), 2);
+        assert_eq!(config.regions.city["tokyo"].city, "Tokyo");
+    }
+
+    #[test]
+    fn test_regions_by_country() {
+        let config: RegionConfig = toml::from_str(TEMPLATE).unwrap();
+
+        // Australian regions span Queensland and New South Wales
+        let cities: Vec<&str> = config
+            .regions_by_country("australia")
+            .iter()
+            .map(|region| region.city.as_str())
+            .collect();
+        assert_eq!(cities, vec!["Gold Coast", "Sydney"]);
+        assert!(config.regions_by_country("Atlantis").is_empty());
+
+        // Each country appears once, even those with several regions
+        let countries = config.countries();
+        let expected: std::collections::HashSet<&str> = config
+            .regions
+            .city
+            .values()
+            .map(|region| region.jurisdiction_country.as_str())
+            .collect();
+        assert!(expected.len() < config.regions.city.len());
+        assert_eq!(countries.len(), expected.len());
+        assert!(countries.iter().all(|c| expected.contains(c.as_str())));
+
+        // Regions in different subdivisions of one jurisdiction are grouped together
+        let config = region_config(&[
+            ("Frankfurt", "European Union"),
+            ("Amsterdam", "European Union"),
+            ("Paris", "European Union"),
+            ("London", "United Kingdom"),
+        ]);
+        let cities: Vec<&str> = config
+            .regions_by_country("European Union")
+            .iter()
+            .map(|region| region.city.as_str())
+            .collect();
+        assert_eq!(cities, vec!["Amsterdam", "Frankfurt", "Paris"]);
+        assert_eq!(config.countries(), vec!["European Union", "United Kingdom"]);
+    }
+}
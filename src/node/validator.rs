@@ -1,6 +1,8 @@
 use commonware_cryptography::Ed25519;
 use commonware_runtime::deterministic::Context as RuntimeContext;
 use std::net::SocketAddr;
+use std::path::Path;
+use std::time::SystemTime;
 use thiserror::Error;
 use tracing::{error, info};
 
@@ -11,8 +13,9 @@ use crate::config::storage::StorageConfig;
 use crate::config::validator::ValidatorConfig;
 use crate::consensus::automaton::BlockchainAutomaton;
 use crate::node::hardware_validator::HardwareDetector;
-use crate::node::hardware_validator::OperatingSystem;
+use crate::node::hardware_validator::HardwareRequirements;
 use crate::node::hardware_validator::VirtualizationType;
+use crate::node::network_validator::validate_validator_location;
 use crate::node::operating_regions::RegionConfig;
 
 #[derive(Error, Debug)]
@@ -27,30 +30,140 @@ pub enum NodeError {
     Initialization(String),
 }
 
+/// Errors that stop a configured node while it is running
+#[derive(Error, Debug)]
+pub enum NodeRunError {
+    /// The journal directory could not be created, or the genesis block could
+    /// not be written to it
+    #[error("Failed to initialize the journal: {0}")]
+    Journal(#[from] std::io::Error),
+
+    /// Measured network latencies do not place the validator in its configured city
+    #[error("Location verification failed for {0}")]
+    Location(String),
+}
+
+/// Whether a node takes part in consensus or only follows the chain
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NodeRole {
+    /// Proposes and verifies blocks; must meet the hardware requirements
+    #[default]
+    Validator,
+    /// Follows and stores the chain without ever proposing
+    Observer,
+}
+
+/// Genesis time shared by test nodes, so every node derives the same genesis block
+#[cfg(test)]
+const TEST_GENESIS_TIME: u64 = 1_700_000_000;
+
 /// The main Node structure that coordinates all components
 pub struct Node {
     runtime: RuntimeContext,
     genesis_config: GenesisConfig,
     storage_config: StorageConfig,
     signer: Ed25519,
+    role: NodeRole,
+    /// City and regions the validator's location is checked against on startup,
+    /// or None to skip the check
+    location: Option<(ValidatorConfig, RegionConfig)>,
 }
 
 impl Node {
     /// Creates a new Node instance with validated configurations
-    pub fn new(runtime: RuntimeContext, signer: Ed25519) -> Result<Self, NodeError> {
-        let (genesis_config, storage_config) = Self::configure_node_context()?;
+    ///
+    /// Configuration is read from `config_dir`, or `./config` when `None`.
+    pub fn new(
+        runtime: RuntimeContext,
+        signer: Ed25519,
+        config_dir: Option<&Path>,
+        role: NodeRole,
+    ) -> Result<Self, NodeError> {
+        let (genesis_config, storage_config) = Self::configure_node_context(config_dir, role)?;
+
+        // Only validators have their location checked
+        let location = match role {
+            NodeRole::Validator => Some(Self::load_location_config(config_dir)?),
+            NodeRole::Observer => None,
+        };
 
         Ok(Self {
             runtime,
             genesis_config,
             storage_config,
             signer,
+            role,
+            location,
         })
     }
 
+    /// Creates a reproducible Node for tests.
+    ///
+    /// The validator key is derived from `seed`, the genesis time is fixed, chain
+    /// data lives under `data_dir`, and hardware verification is skipped, so a
+    /// node built from a deterministic runtime behaves identically on every run.
+    #[cfg(test)]
+    pub fn new_for_test(runtime: RuntimeContext, seed: u64, data_dir: &Path) -> Self {
+        use commonware_cryptography::Scheme;
+
+        let mut genesis_config = GenesisConfig::development();
+        genesis_config.network.genesis_time = TEST_GENESIS_TIME;
+
+        let mut storage_config = StorageConfig::development();
+        storage_config.paths.data_dir = data_dir.to_path_buf();
+
+        Self {
+            runtime,
+            genesis_config,
+            storage_config,
+            signer: Ed25519::from_seed(seed),
+            role: NodeRole::Validator,
+            location: None,
+        }
+    }
+
     /// Loads and validates all required node configurations
     /// Returns a tuple of validated configurations or a NodeError if anything fails
-    fn configure_node_context() -> Result<(GenesisConfig, StorageConfig), NodeError> {
+    fn configure_node_context(
+        config_dir: Option<&Path>,
+        role: NodeRole,
+    ) -> Result<(GenesisConfig, StorageConfig), NodeError> {
+        let genesis_config = GenesisConfig::load_default(config_dir).inspect(|config| {
+            info!("Genesis configuration loaded successfully");
+            info!("Chain ID: {}", config.network.chain_id);
+        })?;
+        genesis_config.validate_genesis_time(SystemTime::now())?;
+
+        // Load Storage configuration
+        let storage_config = StorageConfig::load_default(config_dir).inspect(|_| {
+            info!("Storage configuration loaded successfully");
+        })?;
+        storage_config.initialize_directories()?;
+
+        // Only validators are held to the physical hardware requirements
+        match role {
+            NodeRole::Validator => Self::verify_validator_host(&storage_config)?,
+            NodeRole::Observer => info!("Running as an observer, skipping hardware checks"),
+        }
+
+        Ok((genesis_config, storage_config))
+    }
+
+    /// Loads the validator's configured city and the authorized regions
+    fn load_location_config(
+        config_dir: Option<&Path>,
+    ) -> Result<(ValidatorConfig, RegionConfig), NodeError> {
+        let validator_config = ValidatorConfig::load_validator_config(config_dir).map_err(|e| {
+            NodeError::Initialization(format!("Validator configuration error: {}", e))
+        })?;
+        let regions = RegionConfig::load(config_dir)
+            .map_err(|e| NodeError::Initialization(format!("Region configuration error: {}", e)))?;
+        Ok((validator_config, regions))
+    }
+
+    /// Checks that the host is physical hardware with enough memory, cores and
+    /// free space where chain data will live
+    fn verify_validator_host(storage_config: &StorageConfig) -> Result<(), NodeError> {
         // Detect virtualization
         let virtualization_type = match HardwareDetector::detect_virtualization() {
             Ok(virt_type) => virt_type,
@@ -76,37 +189,172 @@ impl Node {
             }
         }
 
-        let genesis_config = GenesisConfig::load_default().map(|config| {
-            info!("Genesis configuration loaded successfully");
-            info!("Chain ID: {}", config.network.chain_id);
-            config
+        let resources = HardwareDetector::verify_requirements(
+            &HardwareRequirements::default(),
+            &storage_config.paths.data_dir,
+        )
+        .map_err(|e| {
+            error!("Hardware requirements not met: {}", e);
+            NodeError::Initialization(e.to_string())
         })?;
+        info!(
+            "Hardware resources: {} bytes memory, {} cores, {} bytes free disk",
+            resources.total_memory_bytes, resources.cpu_cores, resources.available_disk_bytes
+        );
 
-        // Load Storage configuration
-        let storage_config = StorageConfig::load_default().map(|config| {
-            info!("Storage configuration loaded successfully");
-            config
-        })?;
+        Ok(())
+    }
 
-        Ok((genesis_config, storage_config))
+    /// Builds the automaton that drives this node's block production
+    fn automaton(&self) -> BlockchainAutomaton {
+        BlockchainAutomaton::new(
+            self.runtime.clone(),
+            self.signer.clone(),
+            self.genesis_config.clone(),
+            self.storage_config.clone(),
+            self.role,
+        )
     }
 
+    /// Verifies a validator's location, persists the genesis state and starts the node.
+    ///
+    /// No P2P network is started yet, so the `bootstrap` address is only logged
+    /// and never dialed.
     pub async fn run(
         &self,
         address: SocketAddr,
         bootstrap: Option<SocketAddr>,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+    ) -> Result<(), NodeRunError> {
         info!("Starting node at {}", address);
+        if let Some(bootstrap) = bootstrap {
+            info!("Bootstrap node at {}", bootstrap);
+        }
 
-        let automaton = BlockchainAutomaton::new(
-            self.runtime.clone(),
-            self.signer.clone(),
-            self.genesis_config.clone(),
-            self.storage_config.clone(),
-        );
+        if let Some((validator_config, regions)) = &self.location {
+            let result = validate_validator_location(address.ip(), validator_config, regions).await;
+            if !result.is_verified {
+                return Err(NodeRunError::Location(validator_config.city.clone()));
+            }
+        }
 
-        automaton.run().await?;
+        self.automaton().run().await?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::base58;
+    use bytes::Bytes;
+    use commonware_consensus::{simplex::Context, Automaton, Committer, Supervisor};
+    use commonware_cryptography::Scheme;
+    use commonware_runtime::{deterministic::Executor, Clock, Runner};
+    use std::time::Duration;
+
+    #[test]
+    fn test_seeded_nodes_are_reproducible() {
+        let (executor, runtime, _) = Executor::seeded(42);
+        executor.start(async move {
+            let data_dir = tempfile::tempdir().unwrap();
+            let node = Node::new_for_test(runtime.clone(), 7, data_dir.path());
+            let replica = Node::new_for_test(runtime.clone(), 7, data_dir.path());
+            let other = Node::new_for_test(runtime, 8, data_dir.path());
+
+            assert_eq!(node.signer.public_key(), replica.signer.public_key());
+            assert_ne!(node.signer.public_key(), other.signer.public_key());
+            assert_eq!(
+                node.genesis_config.network.genesis_time,
+                replica.genesis_config.network.genesis_time
+            );
+
+            let address = "127.0.0.1:8000".parse().unwrap();
+            node.run(address, None).await.unwrap();
+            replica.run(address, None).await.unwrap();
+        });
+    }
+
+    #[test]
+    fn test_run_reports_journal_error() {
+        let (executor, runtime, _) = Executor::default();
+        executor.start(async move {
+            // A data directory below a regular file can never be created
+            let blocker = tempfile::NamedTempFile::new().unwrap();
+            let node = Node::new_for_test(runtime, 0, &blocker.path().join("data"));
+
+            let address = "127.0.0.1:8000".parse().unwrap();
+            let result = node.run(address, None).await;
+            assert!(matches!(result, Err(NodeRunError::Journal(_))));
+        });
+    }
+
+    #[test]
+    fn test_two_nodes_finalize_the_same_block() {
+        let (executor, runtime, _) = Executor::seeded(42);
+        executor.start(async move {
+            let data_dirs = [tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap()];
+            let nodes: Vec<Node> = data_dirs
+                .iter()
+                .zip(0..)
+                .map(|(data_dir, seed)| {
+                    let mut node = Node::new_for_test(runtime.clone(), seed, data_dir.path());
+                    node.genesis_config.consensus.block_reward = 5;
+                    node
+                })
+                .collect();
+
+            // Both nodes validate with the same two-member set
+            let validators: Vec<_> = nodes.iter().map(|node| node.signer.public_key()).collect();
+            let mut automatons: Vec<_> = nodes
+                .iter()
+                .map(|node| {
+                    let mut automaton = node.automaton();
+                    automaton.supervisor.update_validators(validators.clone());
+                    automaton
+                })
+                .collect();
+            for automaton in &automatons {
+                automaton.initialize_genesis_state().await.unwrap();
+            }
+
+            // The deterministic clock starts at the Unix epoch; move it to genesis
+            // so proposals are not stamped too far ahead of it
+            runtime
+                .sleep_until(SystemTime::UNIX_EPOCH + Duration::from_secs(TEST_GENESIS_TIME))
+                .await;
+
+            let genesis = automatons[0].genesis().await;
+            let context = || Context {
+                view: 1,
+                parent: (0, genesis.clone()),
+            };
+            let leader = automatons[0].leader(1, ()).unwrap();
+            let proposer = validators.iter().position(|key| *key == leader).unwrap();
+            let payload = automatons[proposer].propose(context()).await.await.unwrap();
+
+            // Stand in for the relay by handing the block to the other node
+            let hash: [u8; 32] = payload.as_ref().try_into().unwrap();
+            let block = automatons[proposer].blocks.lock().unwrap()[&hash].clone();
+            automatons[1 - proposer]
+                .blocks
+                .lock()
+                .unwrap()
+                .insert(hash, block);
+
+            // Every node accepts the leader's block and finalizes it to the same state
+            for automaton in &mut automatons {
+                assert!(automaton
+                    .verify(context(), payload.clone())
+                    .await
+                    .await
+                    .unwrap());
+                automaton.finalized(Bytes::new(), payload.clone()).await;
+            }
+            for automaton in &automatons {
+                let state = automaton.state.lock().unwrap();
+                assert_eq!(state.get_balance(&base58::address(&leader)), 5);
+            }
+        });
+    }
+}
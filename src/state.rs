@@ -0,0 +1,507 @@
+use commonware_cryptography::{Ed25519, Scheme};
+use std::collections::{HashMap, VecDeque};
+use thiserror::Error;
+
+use crate::block::{Block, BlockError, TransactionType};
+use crate::utils::base58;
+
+/// Errors produced when updating account state
+#[derive(Error, Debug, PartialEq)]
+pub enum StateError {
+    /// The account does not hold enough funds for the debit
+    #[error("Insufficient balance for {address}: has {balance}, needs {amount}")]
+    InsufficientBalance {
+        address: String,
+        balance: u64,
+        amount: u64,
+    },
+
+    /// Crediting the account would overflow its balance
+    #[error("Balance overflow for {0}")]
+    BalanceOverflow(String),
+
+    /// The block header does not name a valid proposer key to pay
+    #[error("Invalid proposer key in block {0}")]
+    InvalidProposer(u64),
+
+    /// A different block has already been applied at this height
+    #[error("Conflicting block at height {0}")]
+    ConflictingBlock(u64),
+
+    /// The block sits below the most recently applied height
+    #[error("Block {height} is below the last applied block {last_applied}")]
+    AlreadyApplied { height: u64, last_applied: u64 },
+
+    /// The block is not the direct child of the most recently applied block
+    #[error("Block {height} does not extend the last applied block {last_applied}")]
+    NotNextBlock { height: u64, last_applied: u64 },
+
+    /// The block body is inconsistent with its header
+    #[error("Invalid block: {0}")]
+    InvalidBlock(#[from] BlockError),
+
+    /// No retained checkpoint exists for the requested block
+    #[error("No checkpoint for block {0}")]
+    CheckpointNotFound(u64),
+}
+
+/// Number of checkpoints retained by default
+pub const DEFAULT_MAX_CHECKPOINTS: usize = 64;
+
+/// Account balances keyed by address.
+///
+/// Balances are held in memory only; nothing is written to the journal yet.
+#[derive(Debug, Clone)]
+pub struct StateStore {
+    balances: HashMap<String, u64>,
+    /// Balance snapshots by block number, oldest first, each with the block
+    /// that was last applied when it was taken
+    checkpoints: VecDeque<(u64, HashMap<String, u64>, AppliedBlock)>,
+    max_checkpoints: usize,
+    /// Height and hash of the most recently applied block
+    last_applied: AppliedBlock,
+}
+
+/// Height and hash of an applied block
+type AppliedBlock = (u64, [u8; 32]);
+
+impl StateStore {
+    /// Creates an empty store for the chain starting at `genesis`
+    pub fn new(genesis: &Block) -> Self {
+        Self::with_max_checkpoints(genesis, DEFAULT_MAX_CHECKPOINTS)
+    }
+
+    /// Creates an empty store for the chain starting at `genesis` that retains at
+    /// most `max_checkpoints` checkpoints
+    pub fn with_max_checkpoints(genesis: &Block, max_checkpoints: usize) -> Self {
+        Self {
+            balances: HashMap::new(),
+            checkpoints: VecDeque::new(),
+            max_checkpoints,
+            last_applied: (genesis.header.height, genesis.hash()),
+        }
+    }
+
+    /// Balance of an address, zero for accounts that have never been credited
+    pub fn get_balance(&self, address: &str) -> u64 {
+        self.balances.get(address).copied().unwrap_or(0)
+    }
+
+    pub fn credit(&mut self, address: &str, amount: u64) -> Result<(), StateError> {
+        let balance = self.get_balance(address);
+        let updated = balance
+            .checked_add(amount)
+            .ok_or_else(|| StateError::BalanceOverflow(address.to_string()))?;
+        self.balances.insert(address.to_string(), updated);
+        Ok(())
+    }
+
+    pub fn debit(&mut self, address: &str, amount: u64) -> Result<(), StateError> {
+        let balance = self.get_balance(address);
+        if balance < amount {
+            return Err(StateError::InsufficientBalance {
+                address: address.to_string(),
+                balance,
+                amount,
+            });
+        }
+        self.balances.insert(address.to_string(), balance - amount);
+        Ok(())
+    }
+
+    /// Applies every transaction in a block and pays `reward` plus the block's fees
+    /// to the proposer.
+    ///
+    /// The proposer is the header's validator_public_key, credited at the address
+    /// given by its hex encoding. A header without a valid Ed25519 key rejects the
+    /// block, since there is nobody to pay.
+    ///
+    /// Each transfer costs its sender the amount plus a fee of `gas_amount` base
+    /// units. A sender who can't cover both rejects the block.
+    ///
+    /// The block is applied to a scratch copy and only swapped in once every
+    /// transaction succeeds, so a failing transfer leaves the store untouched.
+    ///
+    /// Blocks must extend the chain one at a time: each block's height and
+    /// previous_hash must follow the last applied block, and its body must match
+    /// its header. Replaying the last applied block is a no-op, so a restart can't
+    /// double-count it. A different block at that height is a
+    /// [`StateError::ConflictingBlock`], and anything lower is
+    /// [`StateError::AlreadyApplied`].
+    pub fn apply_block(&mut self, block: &Block, reward: u64) -> Result<(), StateError> {
+        let height = block.header.height;
+        let hash = block.hash();
+        let (last_applied, last_hash) = self.last_applied;
+        if height == last_applied && hash == last_hash {
+            return Ok(());
+        }
+        if height == last_applied {
+            return Err(StateError::ConflictingBlock(height));
+        }
+        if height < last_applied {
+            return Err(StateError::AlreadyApplied {
+                height,
+                last_applied,
+            });
+        }
+        if height != last_applied + 1 || block.header.previous_hash != last_hash {
+            return Err(StateError::NotNextBlock {
+                height,
+                last_applied,
+            });
+        }
+        block.validate()?;
+
+        let proposer = &block.header.validator_public_key;
+        if !Ed25519::validate(proposer) {
+            return Err(StateError::InvalidProposer(height));
+        }
+        let beneficiary = base58::address(proposer);
+
+        let mut next = Self {
+            balances: self.balances.clone(),
+            checkpoints: VecDeque::new(),
+            max_checkpoints: 0,
+            last_applied: self.last_applied,
+        };
+
+        let mut fees: u64 = 0;
+        for transaction in &block.transactions {
+            let fee = transaction.gas_amount;
+            match &transaction.transaction_type {
+                TransactionType::TokenTransfer { to, amount } => {
+                    let cost = amount
+                        .checked_add(fee)
+                        .ok_or_else(|| StateError::BalanceOverflow(transaction.from.clone()))?;
+                    next.debit(&transaction.from, cost)?;
+                    next.credit(to, *amount)?;
+                }
+            }
+            fees = fees
+                .checked_add(fee)
+                .ok_or_else(|| StateError::BalanceOverflow(beneficiary.clone()))?;
+        }
+        let payout = reward
+            .checked_add(fees)
+            .ok_or_else(|| StateError::BalanceOverflow(beneficiary.clone()))?;
+        next.credit(&beneficiary, payout)?;
+
+        self.balances = next.balances;
+        self.last_applied = (height, hash);
+        Ok(())
+    }
+
+    /// Snapshots the current balances as the state after `block_number`.
+    ///
+    /// Checkpoints at or after `block_number` belong to a chain being replaced and
+    /// are discarded. Only the most recent `max_checkpoints` are kept.
+    pub fn checkpoint(&mut self, block_number: u64) {
+        self.checkpoints
+            .retain(|(number, _, _)| *number < block_number);
+        self.checkpoints
+            .push_back((block_number, self.balances.clone(), self.last_applied));
+
+        while self.checkpoints.len() > self.max_checkpoints {
+            self.checkpoints.pop_front();
+        }
+    }
+
+    /// Reverts balances to the checkpoint taken at `block_number`.
+    ///
+    /// Used when fork choice switches to a chain that diverges after that block.
+    /// Later checkpoints are dropped since they describe the abandoned chain.
+    pub fn rollback_to(&mut self, block_number: u64) -> Result<(), StateError> {
+        let index = self
+            .checkpoints
+            .iter()
+            .position(|(number, _, _)| *number == block_number)
+            .ok_or(StateError::CheckpointNotFound(block_number))?;
+
+        self.checkpoints.truncate(index + 1);
+        let (_, balances, last_applied) = &self.checkpoints[index];
+        self.balances = balances.clone();
+        self.last_applied = *last_applied;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::block::{BlockHeader, Transaction};
+    use commonware_cryptography::{PublicKey, Signature};
+    use std::time::SystemTime;
+
+    fn transfer(from: &str, to: &str, amount: u64) -> Transaction {
+        Transaction {
+            transaction_type: TransactionType::TokenTransfer {
+                to: to.to_string(),
+                amount,
+            },
+            from: from.to_string(),
+            nonce: 0,
+            gas_amount: 0,
+            signature: Signature::from_static(&[0; 64]),
+        }
+    }
+
+    /// Address credited for blocks built by `block`
+    fn proposer() -> String {
+        base58::address(&Ed25519::from_seed(0).public_key())
+    }
+
+    fn genesis() -> Block {
+        Block::genesis(SystemTime::UNIX_EPOCH)
+    }
+
+    /// A block by the test proposer extending `parent`
+    fn block(parent: &Block, transactions: Vec<Transaction>) -> Block {
+        let height = parent.header.height + 1;
+        Block {
+            header: BlockHeader {
+                view: height as u32,
+                height,
+                timestamp: SystemTime::UNIX_EPOCH,
+                previous_hash: parent.hash(),
+                transactions_root: crate::block::merkle::merkle_root(&transactions),
+                state_root: [0; 32],
+                validator_public_key: Ed25519::from_seed(0).public_key(),
+                utilization: 0.0,
+            },
+            transactions,
+        }
+    }
+
+    #[test]
+    fn test_transfer_moves_balance() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        state
+            .apply_block(&block(&genesis(), vec![transfer("alice", "bob", 40)]), 5)
+            .unwrap();
+
+        assert_eq!(state.get_balance("alice"), 60);
+        assert_eq!(state.get_balance("bob"), 40);
+        assert_eq!(state.get_balance(&proposer()), 5);
+    }
+
+    #[test]
+    fn test_fees_paid_to_beneficiary() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let mut first = transfer("alice", "bob", 40);
+        first.gas_amount = 3;
+        let mut second = transfer("alice", "carol", 10);
+        second.gas_amount = 2;
+        let fee_block = block(&genesis(), vec![first, second]);
+        state.apply_block(&fee_block, 5).unwrap();
+
+        assert_eq!(state.get_balance("alice"), 45);
+        assert_eq!(state.get_balance("bob"), 40);
+        assert_eq!(state.get_balance("carol"), 10);
+        assert_eq!(state.get_balance(&proposer()), 10);
+
+        // The fee must be covered on top of the amount
+        let mut overdraft = transfer("alice", "bob", 45);
+        overdraft.gas_amount = 1;
+        assert_eq!(
+            state.apply_block(&block(&fee_block, vec![overdraft]), 5),
+            Err(StateError::InsufficientBalance {
+                address: "alice".to_string(),
+                balance: 45,
+                amount: 46,
+            })
+        );
+        assert_eq!(state.get_balance("alice"), 45);
+    }
+
+    #[test]
+    fn test_proposer_can_spend_reward() {
+        let mut state = StateStore::new(&genesis());
+
+        let rewarded = block(&genesis(), vec![]);
+        state.apply_block(&rewarded, 5).unwrap();
+        assert_eq!(state.get_balance(&proposer()), 5);
+
+        // The reward lands at the address transactions are sent from
+        state
+            .apply_block(&block(&rewarded, vec![transfer(&proposer(), "bob", 5)]), 0)
+            .unwrap();
+        assert_eq!(state.get_balance(&proposer()), 0);
+        assert_eq!(state.get_balance("bob"), 5);
+    }
+
+    #[test]
+    fn test_block_without_proposer_rejected() {
+        let mut state = StateStore::new(&genesis());
+        let mut anonymous = block(&genesis(), vec![]);
+        anonymous.header.validator_public_key = PublicKey::new();
+
+        assert_eq!(
+            state.apply_block(&anonymous, 5),
+            Err(StateError::InvalidProposer(1))
+        );
+    }
+
+    #[test]
+    fn test_replayed_block_is_skipped() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let transfer_block = block(&genesis(), vec![transfer("alice", "bob", 40)]);
+        state.apply_block(&transfer_block, 5).unwrap();
+        state.apply_block(&transfer_block, 5).unwrap();
+
+        assert_eq!(state.get_balance("alice"), 60);
+        assert_eq!(state.get_balance("bob"), 40);
+        assert_eq!(state.get_balance(&proposer()), 5);
+    }
+
+    #[test]
+    fn test_conflicting_block_rejected() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let first = block(&genesis(), vec![transfer("alice", "bob", 40)]);
+        state.apply_block(&first, 5).unwrap();
+        state
+            .apply_block(&block(&first, vec![transfer("alice", "bob", 10)]), 5)
+            .unwrap();
+
+        // A different block at the last applied height is not a replay
+        assert_eq!(
+            state.apply_block(&block(&first, vec![transfer("alice", "carol", 10)]), 5),
+            Err(StateError::ConflictingBlock(2))
+        );
+        assert_eq!(
+            state.apply_block(&first, 5),
+            Err(StateError::AlreadyApplied {
+                height: 1,
+                last_applied: 2,
+            })
+        );
+        assert_eq!(state.get_balance("alice"), 50);
+        assert_eq!(state.get_balance("bob"), 50);
+        assert_eq!(state.get_balance("carol"), 0);
+    }
+
+    #[test]
+    fn test_block_must_extend_last_applied() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let first = block(&genesis(), vec![]);
+        let second = block(&first, vec![transfer("alice", "bob", 40)]);
+
+        // Skipping a height is rejected
+        assert_eq!(
+            state.apply_block(&second, 5),
+            Err(StateError::NotNextBlock {
+                height: 2,
+                last_applied: 0,
+            })
+        );
+
+        // So is a block at the next height built on a different parent
+        let mut orphan = block(&genesis(), vec![]);
+        orphan.header.previous_hash = [7; 32];
+        assert_eq!(
+            state.apply_block(&orphan, 5),
+            Err(StateError::NotNextBlock {
+                height: 1,
+                last_applied: 0,
+            })
+        );
+
+        state.apply_block(&first, 5).unwrap();
+        state.apply_block(&second, 5).unwrap();
+        assert_eq!(state.get_balance("bob"), 40);
+    }
+
+    #[test]
+    fn test_invalid_block_rejected() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let mut tampered = block(&genesis(), vec![transfer("alice", "bob", 40)]);
+        tampered.transactions.push(transfer("alice", "carol", 10));
+
+        assert!(matches!(
+            state.apply_block(&tampered, 5),
+            Err(StateError::InvalidBlock(_))
+        ));
+        assert_eq!(state.get_balance("alice"), 100);
+    }
+
+    #[test]
+    fn test_rollback_to_checkpoint() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let first = block(&genesis(), vec![transfer("alice", "bob", 40)]);
+        state.apply_block(&first, 5).unwrap();
+        state.checkpoint(1);
+
+        state
+            .apply_block(&block(&first, vec![transfer("bob", "carol", 25)]), 5)
+            .unwrap();
+        state.checkpoint(2);
+        assert_eq!(state.get_balance("carol"), 25);
+
+        state.rollback_to(1).unwrap();
+        assert_eq!(state.get_balance("alice"), 60);
+        assert_eq!(state.get_balance("bob"), 40);
+        assert_eq!(state.get_balance("carol"), 0);
+        assert_eq!(state.get_balance(&proposer()), 5);
+
+        // The abandoned block's checkpoint is gone
+        assert_eq!(state.rollback_to(2), Err(StateError::CheckpointNotFound(2)));
+
+        // A competing block at the rolled-back height can be applied
+        state
+            .apply_block(&block(&first, vec![transfer("bob", "dave", 10)]), 5)
+            .unwrap();
+        assert_eq!(state.get_balance("dave"), 10);
+    }
+
+    #[test]
+    fn test_checkpoints_are_bounded() {
+        let mut state = StateStore::with_max_checkpoints(&genesis(), 2);
+        for block_number in 1..=3 {
+            state.credit("alice", 10).unwrap();
+            state.checkpoint(block_number);
+        }
+
+        assert_eq!(state.rollback_to(1), Err(StateError::CheckpointNotFound(1)));
+        state.rollback_to(2).unwrap();
+        assert_eq!(state.get_balance("alice"), 20);
+    }
+
+    #[test]
+    fn test_overdraft_rejected_atomically() {
+        let mut state = StateStore::new(&genesis());
+        state.credit("alice", 100).unwrap();
+
+        let result = state.apply_block(
+            &block(
+                &genesis(),
+                vec![transfer("alice", "bob", 60), transfer("alice", "carol", 60)],
+            ),
+            5,
+        );
+
+        assert_eq!(
+            result,
+            Err(StateError::InsufficientBalance {
+                address: "alice".to_string(),
+                balance: 40,
+                amount: 60,
+            })
+        );
+        assert_eq!(state.get_balance("alice"), 100);
+        assert_eq!(state.get_balance("bob"), 0);
+        assert_eq!(state.get_balance(&proposer()), 0);
+    }
+}
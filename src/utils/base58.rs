@@ -0,0 +1,51 @@
+use commonware_cryptography::PublicKey;
+
+/// Bitcoin Base58 alphabet, which leaves out 0, O, I and l
+const ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Encodes bytes as Base58, keeping each leading zero byte as a '1'
+pub fn encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+
+    // Base58 digits of the value, least significant first
+    let mut digits: Vec<u8> = Vec::with_capacity(bytes.len() * 138 / 100 + 1);
+    for byte in &bytes[zeros..] {
+        let mut carry = u32::from(*byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    std::iter::repeat_n('1', zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+/// The account address of a public key, the Base58 form used in transactions
+pub fn address(public_key: &PublicKey) -> String {
+    encode(public_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_values() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(encode(b"hello world"), "StV1DL6CwTryKyV");
+        assert_eq!(encode(&[0, 0, 1]), "112");
+        assert_eq!(encode(&[0xff; 4]), "7YXq9G");
+    }
+}
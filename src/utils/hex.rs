@@ -0,0 +1,105 @@
+use commonware_cryptography::{Ed25519, PublicKey, Scheme, Signature};
+use thiserror::Error;
+
+/// Errors produced when parsing hex-encoded hashes and keys
+#[derive(Error, Debug, PartialEq)]
+pub enum HexError {
+    /// The input contained non-hex characters or had an odd length
+    #[error("Invalid hex: {0}")]
+    Hex(String),
+
+    /// The decoded value was not the expected number of bytes
+    #[error("Expected {expected} bytes, got {actual}")]
+    Length { expected: usize, actual: usize },
+
+    /// The decoded bytes are not a valid public key
+    #[error("Invalid public key")]
+    PublicKey,
+}
+
+/// Encodes a 32-byte hash as lowercase hex
+pub fn encode_hash(hash: &[u8; 32]) -> String {
+    ::hex::encode(hash)
+}
+
+/// Decodes a 64-character hex string into a 32-byte hash
+pub fn decode_hash(s: &str) -> Result<[u8; 32], HexError> {
+    let bytes = decode_exact(s, 32)?;
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&bytes);
+    Ok(hash)
+}
+
+/// Decodes a hex string into an Ed25519 public key, validating its format
+pub fn decode_pubkey(s: &str) -> Result<PublicKey, HexError> {
+    let (key_len, _) = Ed25519::len();
+    let public_key = PublicKey::from(decode_exact(s, key_len)?);
+    if !Ed25519::validate(&public_key) {
+        return Err(HexError::PublicKey);
+    }
+    Ok(public_key)
+}
+
+/// Decodes a hex string into an Ed25519 signature
+pub fn decode_signature(s: &str) -> Result<Signature, HexError> {
+    let (_, signature_len) = Ed25519::len();
+    Ok(Signature::from(decode_exact(s, signature_len)?))
+}
+
+/// Decodes hex input that must produce exactly `expected` bytes
+fn decode_exact(s: &str, expected: usize) -> Result<Vec<u8>, HexError> {
+    let bytes = ::hex::decode(s).map_err(|e| HexError::Hex(e.to_string()))?;
+    if bytes.len() != expected {
+        return Err(HexError::Length {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_round_trip() {
+        let hash = [0xab; 32];
+        let encoded = encode_hash(&hash);
+        assert_eq!(encoded.len(), 64);
+        assert_eq!(decode_hash(&encoded), Ok(hash));
+    }
+
+    #[test]
+    fn test_decode_hash_wrong_length() {
+        assert_eq!(
+            decode_hash("abcd"),
+            Err(HexError::Length {
+                expected: 32,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_hash_rejects_non_hex() {
+        let odd_length = "a".repeat(63);
+        assert!(matches!(
+            decode_hash(&odd_length),
+            Err(HexError::Hex(_))
+        ));
+
+        let non_hex = "z".repeat(64);
+        assert!(matches!(
+            decode_hash(&non_hex),
+            Err(HexError::Hex(_))
+        ));
+    }
+
+    #[test]
+    fn test_decode_pubkey() {
+        let public_key = Ed25519::from_seed(0).public_key();
+        assert_eq!(decode_pubkey(&::hex::encode(&public_key)), Ok(public_key));
+        assert!(decode_pubkey("00").is_err());
+    }
+}
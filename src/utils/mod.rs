@@ -1 +1,2 @@
-pub mod utils;
\ No newline at end of file
+pub mod base58;
+pub mod hex;
\ No newline at end of file